@@ -3,12 +3,58 @@
 #[ink::contract]
 mod voting_system {
 
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::env::hash::Blake2x256;
+    use ink::prelude::boxed::Box;
     use ink::prelude::string::String;
-    use ink::storage::Mapping;
+    use ink::prelude::vec::Vec;
+    use ink::scale::Encode;
+    use ink::storage::{Lazy, Mapping};
 
-    /// =========================
-    /// MODELO
-    /// =========================
+    /// Cantidad máxima de propuestas devueltas por página en los listados.
+    pub const MAX_PAGE_SIZE: u32 = 100;
+    /// Cantidad máxima de elementos aceptados en una operación por lotes.
+    pub const MAX_BATCH_SIZE: u32 = 50;
+    /// Cantidad máxima de propuestas aceptadas en una consulta por lotes.
+    pub const MAX_LOOKUP_SIZE: u32 = 200;
+    /// Cantidad máxima de etiquetas por propuesta.
+    pub const MAX_TAGS: u32 = 5;
+    /// Cantidad máxima de opciones en una propuesta de opción múltiple.
+    pub const MAX_OPTIONS: u32 = 32;
+    /// Cantidad máxima de saltos en una cadena de delegación.
+    pub const MAX_DELEGATION_DEPTH: u32 = 10;
+    /// Longitud máxima por defecto, en bytes, del título de una propuesta.
+    pub const DEFAULT_MAX_TITLE_LEN: u32 = 256;
+    /// Peso máximo de un voto emitido al abrir la votación en modo de decaimiento, por unidad de peso.
+    /// En ese modo los contadores de votos se expresan en centésimos de voto.
+    pub const DECAY_SCALE: u64 = 100;
+    /// Duración por defecto, en bloques, de la fase de revelación de las votaciones secretas.
+    pub const DEFAULT_REVEAL_PERIOD: u32 = 100;
+    /// Longitud máxima, en bytes, de la descripción de una propuesta.
+    pub const MAX_DESCRIPTION_LEN: u32 = 4096;
+    /// Cantidad máxima de descripciones anteriores devueltas por página en `get_edit_history`.
+    /// Es menor que `MAX_PAGE_SIZE` porque cada una puede ocupar hasta `MAX_DESCRIPTION_LEN` bytes.
+    pub const MAX_EDIT_PAGE_SIZE: u32 = 3;
+    /// Longitud máxima, en bytes, del texto buscado en `search_proposals`.
+    pub const MAX_SEARCH_LEN: u32 = 64;
+    /// Etiqueta con la que empieza todo mensaje firmado para `vote_with_sig`, para que la firma no
+    /// pueda confundirse con la de otro protocolo.
+    pub const VOTE_SIG_CONTEXT: &[u8] = b"sysV:vote_with_sig";
+
+    /// Hash Blake2x256 de un voto secreto y su sal.
+    pub type Commitment = [u8; 32];
+    /// Índices de opciones ordenados de la más a la menos preferida.
+    pub type Ranking = Vec<u32>;
+    /// Índices de las opciones aprobadas en un voto por aprobación.
+    pub type Approval = Vec<u32>;
+    /// Pares de índice de opción y puntos asignados en un voto acumulativo.
+    pub type Allocation = Vec<(u32, u32)>;
+    /// Propuesta, delegado y posición de un delegante cuyo peso sumó ese delegado.
+    type CarriedKey = (u32, AccountId, u32);
+
+    // =========================
+    // MODELO
+    // =========================
 
 
     /// Definición de posibles errores durante la ejecución del contrato.
@@ -20,20 +66,733 @@ mod voting_system {
     pub enum Error {
         /// El usuario no tiene permisos para realizar la acción (solo el owner).
         OnlyOwnerCanPerformAction,
+        /// El caller no es owner ni moderador.
+        Unauthorized,
         /// El identificador de propuesta proporcionado no existe en el storage.
         ProposalDoesNotExist,
         /// La cuenta ya ha emitido un voto para esta propuesta específica.
         AlreadyVoted,
+        /// El período de votación de la propuesta ya finalizó.
+        VotingClosed,
+        /// No pasaron suficientes bloques desde el último voto de la cuenta.
+        VotingTooSoon,
+        /// La votación de la propuesta todavía no comenzó.
+        VotingNotStarted,
+        /// El bloque de inicio no es anterior al cierre de la votación.
+        InvalidStartBlock,
+        /// El bloque de vencimiento de la delegación ya pasó.
+        InvalidExpiry,
+        /// La fracción de aprobación es inválida: el numerador supera al denominador.
+        InvalidThreshold,
+        /// La propuesta fue cerrada manualmente por el owner.
+        ProposalClosed,
+        /// La propuesta no está cerrada.
+        ProposalNotClosed,
+        /// La propuesta no se encuentra en estado activo.
+        ProposalNotActive,
+        /// La propuesta fue cancelada por su creador.
+        ProposalCancelled,
+        /// La propuesta ya recibió votos.
+        ProposalHasVotes,
+        /// La propuesta ya no está en borrador.
+        NotDraft,
+        /// La cuenta ya patrocinó esta propuesta.
+        AlreadySponsored,
+        /// El caller no es la cuenta propuesta como nuevo owner.
+        NotPendingOwner,
+        /// La cuenta nula no es válida para esta operación.
+        ZeroAccount,
+        /// Se recibió un conjunto vacío de propuestas.
+        EmptyProposalSet,
+        /// El lote supera la cantidad máxima de elementos permitida.
+        BatchTooLarge,
+        /// La propuesta supera la cantidad máxima de etiquetas.
+        TooManyTags,
+        /// El título de la propuesta está vacío.
+        EmptyTitle,
+        /// El título de la propuesta supera la longitud máxima configurada.
+        TitleTooLong,
+        /// La descripción de la propuesta supera `MAX_DESCRIPTION_LEN`.
+        DescriptionTooLong,
+        /// La URI de metadatos supera la longitud máxima permitida.
+        MetadataTooLong,
+        /// El motivo del voto supera la longitud máxima permitida.
+        ReasonTooLong,
+        /// Ya existe una propuesta con el mismo título.
+        DuplicateTitle,
+        /// Una cuenta no puede delegar su voto en sí misma.
+        CannotDelegateToSelf,
+        /// La delegación cerraría un ciclo entre cuentas.
+        DelegationCycle,
+        /// La cadena de delegación supera `MAX_DELEGATION_DEPTH` saltos.
+        DelegationTooDeep,
+        /// La cuenta delegó su voto y no puede votar directamente.
+        VoteDelegated,
+        /// La cuenta no tiene una delegación activa.
+        NotDelegated,
+        /// La propuesta no admite esta forma de votación.
+        WrongVotingMode,
+        /// La cuenta ya registró un compromiso de voto secreto.
+        AlreadyCommitted,
+        /// El voto revelado no coincide con el compromiso registrado.
+        CommitmentMismatch,
+        /// La firma no corresponde al votante y al mensaje.
+        InvalidSignature,
+        /// La prueba de Merkle no demuestra que la cuenta esté habilitada.
+        InvalidProof,
+        /// El nonce no coincide con el esperado para el votante.
+        BadNonce,
+        /// Todavía no comenzó el período de revelación de votos.
+        RevealNotStarted,
+        /// El valor transferido no cubre el depósito requerido para crear propuestas.
+        InsufficientDeposit,
+        /// La votación de la propuesta sigue abierta.
+        VotingStillOpen,
+        /// El índice de opción no existe en la propuesta.
+        InvalidOption,
+        /// Un mismo índice de opción aparece más de una vez.
+        DuplicateOption,
+        /// Los puntos asignados superan el presupuesto de la propuesta.
+        BudgetExceeded,
+        /// La propuesta no tiene votos para contar.
+        NoVotes,
+        /// La propuesta supera la cantidad máxima de opciones.
+        TooManyOptions,
+        /// Se alcanzó la cantidad máxima de propuestas del contrato.
+        ProposalLimitReached,
+        /// El contador de IDs de propuestas o de acciones de gobierno llegó a `u32::MAX`.
+        ProposalCounterOverflow,
+        /// La cuenta no tiene créditos suficientes para el peso pedido.
+        InsufficientCredits,
+        /// El peso del voto es 0, ya sea el asignado por el owner o el pedido en un voto
+        /// cuadrático, por lo que no contaría.
+        ZeroWeight,
+        /// La reputación de la cuenta es menor a la exigida por la propuesta.
+        InsufficientReputation,
+        /// La cuenta no figura en la lista de votantes habilitados.
+        NotEligible,
+        /// El owner no puede votar con la configuración actual.
+        OwnerCannotVote,
+        /// El creador de la propuesta no puede votar en ella con la configuración actual.
+        CreatorCannotVote,
+        /// No hay votantes registrados para calcular la participación.
+        NoRegisteredVoters,
+        /// El período de registro de votantes no está abierto.
+        RegistrationClosed,
+        /// La cuenta ya está registrada como votante.
+        AlreadyRegistered,
+        /// La cuenta fue bloqueada por el owner.
+        AccountBlacklisted,
+        /// El saldo del token de gobernanza es menor al mínimo exigido.
+        InsufficientTokenBalance,
+        /// Falló la consulta de saldo al contrato del token de gobernanza.
+        TokenCallFailed,
+        /// El token de gobernanza no permite consultar saldos históricos.
+        SnapshotUnsupported,
+        /// No hay fondos pendientes de reembolso.
+        NothingToRefund,
+        /// La propuesta no alcanzó el quórum o el umbral de aprobación.
+        ProposalNotPassed,
+        /// La acción de la propuesta, o la acción de gobierno, ya fue ejecutada.
+        AlreadyExecuted,
+        /// La propuesta ya fue finalizada y su resultado es definitivo.
+        AlreadyFinalized,
+        /// La propuesta todavía no fue finalizada.
+        NotFinalized,
+        /// Falló la llamada al contrato destino de la propuesta.
+        ExecutionFailed,
+        /// La propuesta no tiene una acción para ejecutar.
+        NotExecutable,
+        /// La propuesta de la que depende no fue finalizada como aprobada.
+        DependencyNotMet,
+        /// No hay una instantánea registrada para ese bloque y propuesta.
+        SnapshotNotFound,
+        /// La cuenta no registra un voto en esta propuesta.
+        HasNotVoted,
+        /// La acción de gobierno no reúne las aprobaciones requeridas.
+        NotEnoughApprovals,
+        /// No existe una acción de gobierno con ese ID.
+        ActionDoesNotExist,
+        /// El owner ya aprobó esta acción de gobierno.
+        AlreadyApproved,
+        /// Falló la transferencia de fondos desde el contrato.
+        TransferFailed,
+        /// El monto supera los fondos libres de la tesorería.
+        InsufficientTreasury,
+        /// La nueva opción coincide con la ya registrada.
+        SameChoice,
+        /// Los votos están pausados por el owner.
+        VotingPaused,
+        /// La creación de propuestas está pausada por el owner.
+        CreationPaused,
+        /// Se intentó reingresar a un mensaje mientras otro seguía en ejecución.
+        Reentrancy,
+        /// No se pudo reemplazar el código del contrato.
+        UpgradeFailed,
+        /// La propuesta no fue cancelada ni vetada, por lo que sus aportes no se reembolsan.
+        NotCancelled,
+        /// Terminó el período de revelación de votos.
+        RevealEnded,
+    }
+    /// Opciones disponibles al emitir un voto.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum VoteChoice {
+        /// Voto a favor de la propuesta.
+        Yes,
+        /// Voto en contra de la propuesta.
+        No,
+        /// Abstención: se registra la participación sin apoyar ni rechazar.
+        Abstain,
+        /// Voto por la opción con el índice indicado, en propuestas de opción múltiple.
+        Option(u32),
+        /// Voto negativo, que resta del puntaje neto de la propuesta.
+        Downvote,
+    }
+    /// Estados posibles en el ciclo de vida de una propuesta.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum ProposalStatus {
+        /// En preparación, todavía no acepta votos.
+        Draft,
+        /// Abierta a votación.
+        Active,
+        /// Votación terminada, manualmente o por plazo.
+        Closed,
+        /// Resultado definitivo registrado.
+        Finalized,
+        /// Cancelada por su creador antes de recibir votos.
+        Cancelled,
     }
     /// Estructura que representa una propuesta en el almacenamiento persistente.
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
     #[derive(Clone, Debug, PartialEq, Eq)]
     pub struct Proposal {
-        /// Título o descripción detallada de la propuesta.
+        /// Título de la propuesta.
+        pub title: String,
+        /// Descripción detallada; vacía si no se indicó.
+        pub description: String,
+        /// Contador acumulado de votos a favor.
+        pub yes_votes: u32,
+        /// Contador acumulado de votos en contra.
+        pub no_votes: u32,
+        /// Contador acumulado de abstenciones.
+        pub abstain_votes: u32,
+        /// Contador acumulado de votos negativos.
+        pub downvotes: u32,
+        /// Bloque a partir del cual ya no se aceptan votos.
+        pub end_block: u32,
+        /// Participación mínima (votos totales) para que el resultado sea válido.
+        pub quorum: u32,
+        /// Votos a favor necesarios para que la propuesta se considere aprobada.
+        pub threshold: u32,
+        /// Estado actual del ciclo de vida de la propuesta.
+        pub status: ProposalStatus,
+        /// Etiquetas para categorizar la propuesta.
+        pub tags: Vec<String>,
+        /// En votaciones secretas, bloque a partir del cual se aceptan las revelaciones durante
+        /// `settings.reveal_period` bloques.
+        pub reveal_block: Option<u32>,
+        /// Depósito pendiente de reembolso, en 0 una vez devuelto.
+        pub deposit: Balance,
+        /// Cuenta que creó la propuesta; recibe el reembolso del depósito.
+        pub creator: AccountId,
+        /// Marca de tiempo del bloque en que se creó la propuesta.
+        pub created_at: Timestamp,
+        /// Etiquetas de las opciones; vacío en propuestas de sí/no.
+        pub options: Vec<String>,
+        /// Votos recibidos por cada opción, en el mismo orden que `options`.
+        pub option_votes: Vec<u32>,
+        /// URI del contenido extendido fuera de la cadena; vacío si no tiene.
+        pub metadata_uri: String,
+        /// Llamada a ejecutar si la propuesta se aprueba.
+        pub action: Option<ProposalAction>,
+        /// Bloque a partir del cual se aceptan votos.
+        pub start_block: u32,
+        /// Participación mínima, en porcentaje de los votantes registrados.
+        pub min_turnout: u32,
+        /// Numerador de la fracción de votos a favor, sobre los sí y no, necesaria para aprobar.
+        pub pass_numerator: u32,
+        /// Denominador de la fracción de aprobación.
+        pub pass_denominator: u32,
+        /// Cantidad de cuentas distintas que patrocinaron la propuesta.
+        pub sponsor_count: u32,
+        /// Votos a favor congelados al finalizar.
+        pub final_votes: u32,
+        /// Resultado congelado al finalizar: si alcanzó el quórum y el umbral.
+        pub passed: bool,
+        /// Indica si la acción de la propuesta ya se ejecutó.
+        pub executed: bool,
+        /// Raíz de Merkle de las cuentas habilitadas; si existe, reemplaza a la lista de votantes.
+        pub eligibility_root: Option<[u8; 32]>,
+        /// Propuesta que debe estar finalizada y aprobada antes de ejecutar esta.
+        pub depends_on: Option<u32>,
+        /// Puntos que cada votante reparte entre las opciones en el voto acumulativo; 0 si no lo admite.
+        pub point_budget: u32,
+        /// Peso máximo con el que cuenta un votante; 0 si no tiene tope.
+        pub max_weight_per_voter: u32,
+        /// Indica si el owner vetó la propuesta, que entonces se considera rechazada.
+        pub vetoed: bool,
+        /// Si es verdadero, la votación se cierra con el voto que alcanza el quórum.
+        pub auto_close_on_quorum: bool,
+        /// Reputación mínima que debe tener una cuenta para votar; 0 si no se exige.
+        pub min_reputation: u32,
+        /// Bloque de creación, en el que se mide el saldo del token de gobernanza de los votantes.
+        pub snapshot_block: u32,
+        /// Unidades de los contadores que equivalen a un voto: `DECAY_SCALE` en modo de decaimiento
+        /// y 1 en los demás. El quórum, el umbral y el tope por votante se expresan en votos.
+        pub vote_unit: u32,
+        /// Peso que las papeletas de aprobación suman a partir de su segunda opción. `total_votes`
+        /// lo descuenta para que cada papeleta cuente una sola vez en la participación.
+        pub approval_overlap: u32,
+    }
+
+    /// Vista pública de una propuesta, con su estado calculado al momento de la consulta.
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ProposalView {
+        /// Identificador de la propuesta.
+        pub id: u32,
+        /// Título de la propuesta.
+        pub title: String,
+        /// Descripción detallada; vacía si no se indicó.
         pub description: String,
-        /// Contador acumulado de votos recibidos.
-        pub votes: u32
+        /// Votos a favor.
+        pub yes_votes: u32,
+        /// Votos en contra.
+        pub no_votes: u32,
+        /// Abstenciones.
+        pub abstain_votes: u32,
+        /// Votos negativos.
+        pub downvotes: u32,
+        /// Participación mínima para que el resultado sea válido.
+        pub quorum: u32,
+        /// Votos a favor necesarios para aprobar la propuesta.
+        pub threshold: u32,
+        /// Estado actual, considerando el plazo de votación.
+        pub status: ProposalStatus,
+        /// Bloque a partir del cual ya no se aceptan votos.
+        pub end_block: u32,
+        /// Cuenta que creó la propuesta.
+        pub creator: AccountId,
+        /// Marca de tiempo del bloque en que se creó la propuesta.
+        pub created_at: Timestamp,
+        /// Etiquetas de las opciones; vacío en propuestas de sí/no.
+        pub options: Vec<String>,
+        /// Votos recibidos por cada opción.
+        pub option_votes: Vec<u32>,
+        /// Patrocinios recibidos mientras estuvo en borrador.
+        pub sponsor_count: u32,
+        /// Puntos por votante para el voto acumulativo; 0 si no lo admite.
+        pub point_budget: u32,
+        /// Unidades de los contadores que equivalen a un voto, según `Proposal::vote_unit`.
+        pub vote_unit: u32,
+    }
+
+    /// Resultado de una propuesta con su quórum y su regla de aprobación ya evaluados.
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ProposalResult {
+        /// Votos a favor.
+        pub votes: u32,
+        /// Votos totales, que son los que cuentan para el quórum.
+        pub total_votes: u32,
+        /// Participación mínima exigida.
+        pub quorum: u32,
+        /// Si los votos totales alcanzan el quórum.
+        pub quorum_met: bool,
+        /// Votos a favor necesarios para aprobar.
+        pub threshold: u32,
+        /// Si la propuesta se aprueba: quórum, umbral y mayoría, o el resultado congelado si ya
+        /// fue finalizada.
+        pub passed: bool,
+        /// Estado calculado al momento de la consulta.
+        pub status: ProposalStatus,
+    }
+
+    /// Parámetros opcionales para la creación de propuestas.
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    pub struct ProposalParams {
+        /// Cantidad de bloques que la votación permanece abierta; con 0 nace cerrada.
+        pub duration_blocks: u32,
+        /// Cantidad mínima de votos para considerar válido el resultado.
+        pub quorum: u32,
+        /// Votos a favor necesarios para aprobar la propuesta; con 0 se aprueba siempre.
+        pub threshold: u32,
+        /// Etiquetas de la propuesta, hasta `MAX_TAGS`.
+        pub tags: Vec<String>,
+        /// Si es verdadero, se vota en dos fases: compromisos durante el plazo y
+        /// revelaciones una vez vencido.
+        pub commit_reveal: bool,
+        /// Etiquetas de las opciones, hasta `MAX_OPTIONS`. Si no está vacío la propuesta es de
+        /// opción múltiple y se vota con `VoteChoice::Option`; con una sola opción funciona
+        /// como un contador único.
+        pub options: Vec<String>,
+        /// URI del contenido extendido fuera de la cadena (por ejemplo un CID de IPFS).
+        /// Vacío si la propuesta no tiene metadatos.
+        pub metadata_uri: String,
+        /// Llamada a ejecutar con `execute` si la propuesta se aprueba.
+        pub action: Option<ProposalAction>,
+        /// Bloque a partir del cual se aceptan votos; con 0 o un bloque pasado abre de inmediato.
+        /// El plazo de `duration_blocks` se sigue contando desde la creación.
+        pub start_block: u32,
+        /// Participación mínima, en porcentaje de los votantes registrados; con 0 no se exige.
+        pub min_turnout: u32,
+        /// Fracción de votos a favor necesaria para aprobar, por ejemplo 2/3. Con 0/0 se usa
+        /// mayoría simple (1/2).
+        pub pass_numerator: u32,
+        /// Denominador de la fracción de aprobación.
+        pub pass_denominator: u32,
+        /// Propuesta existente que debe aprobarse y finalizarse antes de poder ejecutar esta.
+        pub depends_on: Option<u32>,
+        /// Presupuesto de puntos por votante para el voto acumulativo en propuestas de opción
+        /// múltiple. Con 0 la propuesta no admite voto acumulativo.
+        pub point_budget: u32,
+        /// Tope del peso efectivo de cada votante, incluidas sus delegaciones. Con 0 no hay tope.
+        pub max_weight_per_voter: u32,
+        /// Si es verdadero, el voto que lleva el total al quórum cierra la votación. Con quórum 0
+        /// la cierra el primer voto.
+        pub auto_close_on_quorum: bool,
+        /// Reputación mínima, asignada por el owner con `set_reputation`, necesaria para votar.
+        /// Con 0 no se exige; si es mayor, las delegaciones no suman peso.
+        pub min_reputation: u32,
+    }
+
+    /// Llamada a otro contrato que ejecuta una propuesta aprobada.
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ProposalAction {
+        /// Contrato al que se llama.
+        pub target: AccountId,
+        /// Selector del mensaje a invocar.
+        pub selector: [u8; 4],
+        /// Argumentos del mensaje ya codificados en SCALE.
+        pub input: Vec<u8>,
+    }
+
+    /// Argumentos ya codificados, que se copian tal cual en la llamada.
+    struct EncodedInput<'a>(&'a [u8]);
+
+    impl Encode for EncodedInput<'_> {
+        fn size_hint(&self) -> usize {
+            self.0.len()
+        }
+
+        fn encode_to<T: ink::scale::Output + ?Sized>(&self, dest: &mut T) {
+            dest.write(self.0);
+        }
+    }
+
+    /// Acción privilegiada que, en modo multifirma, requiere la aprobación de varios owners.
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum GovernanceAction {
+        /// Crear una propuesta con título, descripción y parámetros.
+        CreateProposal {
+            title: String,
+            description: String,
+            params: Box<ProposalParams>,
+        },
+        /// Cerrar la votación de una propuesta.
+        CloseProposal(u32),
+        /// Pausar los votos y la creación de propuestas.
+        Pause,
+        /// Reanudar los votos y la creación de propuestas.
+        Unpause,
+        /// Pausar o reanudar solo los votos.
+        SetVotingPaused(bool),
+        /// Pausar o reanudar solo la creación de propuestas.
+        SetCreationPaused(bool),
+        /// Eliminar una propuesta, devolviendo sus fondos retenidos.
+        DeleteProposal(u32),
+        /// Transferir fondos libres de la tesorería.
+        WithdrawTreasury { amount: Balance, to: AccountId },
+        /// Reemplazar el código del contrato.
+        SetCodeHash(Hash),
+        /// Crear varias propuestas con los mismos parámetros.
+        CreateProposals { titles: Vec<String>, params: Box<ProposalParams> },
+        /// Cancelar una propuesta no finalizada, aunque tenga votos.
+        ForceCancel(u32),
+        /// Corregir el quórum y el umbral de una propuesta sin votos.
+        UpdateThresholds { proposal_id: u32, quorum: u32, threshold: u32 },
+        /// Extender el plazo de una propuesta abierta.
+        ExtendDeadline { proposal_id: u32, additional_blocks: u32 },
+        /// Reabrir una propuesta cerrada.
+        ReopenProposal { proposal_id: u32, additional_blocks: u32 },
+        /// Vetar una propuesta aprobada.
+        Veto(u32),
+        /// Registrar una instantánea de los votos a favor.
+        Snapshot,
+        /// Bloquear a una cuenta.
+        Blacklist(AccountId),
+        /// Desbloquear a una cuenta.
+        Unblacklist(AccountId),
+        /// Designar a un moderador.
+        AddModerator(AccountId),
+        /// Quitar a un moderador.
+        RemoveModerator(AccountId),
+        /// Habilitar a una cuenta para votar.
+        AddVoter(AccountId),
+        /// Quitar a una cuenta de los votantes habilitados.
+        RemoveVoter(AccountId),
+        /// Fijar el período de registro de votantes.
+        OpenRegistration { from: u32, to: u32 },
+        /// Fijar o quitar la raíz de elegibilidad de una propuesta.
+        SetEligibilityRoot { proposal_id: u32, root: Option<[u8; 32]> },
+        /// Activar o desactivar la restricción de votantes.
+        SetAllowlistEnabled(bool),
+        /// Otorgar créditos de voto cuadrático.
+        GrantCredits { account: AccountId, amount: u64 },
+        /// Asignar la reputación de una cuenta.
+        SetReputation { account: AccountId, value: u32 },
+        /// Asignar el peso de voto de una cuenta.
+        SetWeight { account: AccountId, weight: u32 },
+        /// Proponer un nuevo owner, que debe aceptar la propiedad.
+        TransferOwnership(AccountId),
+        /// Renunciar a la propiedad del contrato.
+        RenounceOwnership,
+    }
+
+    /// Acción de gobierno propuesta por un owner, con las aprobaciones reunidas.
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct PendingAction {
+        /// Acción a ejecutar.
+        pub action: GovernanceAction,
+        /// Owner que la propuso.
+        pub proposer: AccountId,
+        /// Cantidad de owners distintos que la aprobaron, incluido quien la propuso.
+        pub approvals: u32,
+        /// Si es verdadero, la acción ya se ejecutó.
+        pub executed: bool,
+    }
+
+    /// Configuración del contrato fijada al desplegarlo.
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Settings {
+        /// Si es verdadero, el peso de cada voto es el valor transferido con él.
+        pub value_weighted: bool,
+        /// Longitud máxima, en bytes, del título de una propuesta.
+        pub max_title_len: u32,
+        /// Si es verdadero, se rechazan títulos repetidos entre las propuestas existentes.
+        pub dedupe_titles: bool,
+        /// Depósito exigido por cada propuesta creada, reembolsable al terminar la votación.
+        pub proposal_deposit: Balance,
+        /// Si es verdadero, solo votan las cuentas habilitadas por el owner.
+        pub allowlist_enabled: bool,
+        /// Contrato PSP22 cuyo saldo habilita a votar. Sin token no se exige saldo.
+        pub governance_token: Option<AccountId>,
+        /// Saldo mínimo del token de gobernanza necesario para votar.
+        pub min_token_balance: Balance,
+        /// Cantidad máxima de propuestas que pueden crearse. Con 0 no hay límite.
+        pub max_proposals: u32,
+        /// Patrocinios necesarios para que una propuesta salga de borrador. Con 0 nace activa.
+        pub min_sponsors: u32,
+        /// Bloques que deben pasar entre dos votos de una misma cuenta. Con 0 no hay espera.
+        pub vote_cooldown: u32,
+        /// Permite que el owner vote. Si es falso, la restricción sigue al owner vigente.
+        pub owner_can_vote: bool,
+        /// Permite que el creador de una propuesta vote en ella. Si es falso, la restricción se
+        /// aplica al creador registrado en cada propuesta, sea o no el owner actual.
+        pub creator_can_vote: bool,
+        /// Modo de decaimiento: el peso de cada voto se escala según los bloques que le faltan a la
+        /// votación, de modo que los votos tempranos cuentan más que los de último momento.
+        pub decay_mode: bool,
+        /// Owners que gobiernan las acciones multifirma. Vacío equivale a solo quien despliega.
+        pub owners: Vec<AccountId>,
+        /// Desempate pseudoaleatorio en `winner` y `tally_irv`, derivado del bloque actual en lugar
+        /// del índice más bajo o más alto. No es criptográficamente seguro: el productor del bloque
+        /// puede anticipar el resultado.
+        pub tie_break_random: bool,
+        /// Aprobaciones de owners distintos necesarias para ejecutar una acción de gobierno. Se
+        /// ajusta al rango de 1 a la cantidad de owners; con más de 1, toda acción del owner o de
+        /// los moderadores solo se hace mediante `propose_action`.
+        pub required_approvals: u32,
+        /// Bloques durante los que se aceptan revelaciones en las votaciones secretas, contados
+        /// desde el `reveal_block` de cada propuesta. Se ajusta a un mínimo de 1.
+        pub reveal_period: u32,
+    }
+
+    impl Default for Settings {
+        fn default() -> Self {
+            Self {
+                value_weighted: false,
+                max_title_len: DEFAULT_MAX_TITLE_LEN,
+                dedupe_titles: false,
+                proposal_deposit: 0,
+                allowlist_enabled: false,
+                governance_token: None,
+                min_token_balance: 0,
+                max_proposals: 0,
+                min_sponsors: 0,
+                vote_cooldown: 0,
+                owner_can_vote: true,
+                creator_can_vote: true,
+                decay_mode: false,
+                tie_break_random: false,
+                owners: Vec::new(),
+                required_approvals: 1,
+                reveal_period: DEFAULT_REVEAL_PERIOD,
+            }
+        }
+    }
+
+    /// Configuración vigente del contrato, reunida en una sola consulta.
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Config {
+        /// Owner actual; la cuenta nula si se renunció a la propiedad.
+        pub owner: AccountId,
+        /// Cuenta propuesta como nuevo owner, pendiente de aceptar.
+        pub pending_owner: Option<AccountId>,
+        /// Owners que gobiernan las acciones multifirma.
+        pub owners: Vec<AccountId>,
+        /// Aprobaciones necesarias para ejecutar una acción de gobierno.
+        pub required_approvals: u32,
+        /// Cantidad máxima de propuestas; 0 si no hay límite.
+        pub max_proposals: u32,
+        /// Depósito exigido por cada propuesta creada.
+        pub proposal_deposit: Balance,
+        /// Longitud máxima, en bytes, del título de una propuesta.
+        pub max_title_len: u32,
+        /// Si se rechazan títulos repetidos.
+        pub dedupe_titles: bool,
+        /// Si el peso de cada voto es el valor transferido con él.
+        pub value_weighted: bool,
+        /// Si solo votan las cuentas habilitadas.
+        pub allowlist_enabled: bool,
+        /// Contrato PSP22 cuyo saldo habilita a votar, si hay.
+        pub governance_token: Option<AccountId>,
+        /// Saldo mínimo del token de gobernanza necesario para votar.
+        pub min_token_balance: Balance,
+        /// Patrocinios necesarios para que una propuesta salga de borrador.
+        pub min_sponsors: u32,
+        /// Bloques que deben pasar entre dos votos de una misma cuenta.
+        pub vote_cooldown: u32,
+        /// Si el owner puede votar.
+        pub owner_can_vote: bool,
+        /// Si el creador de una propuesta puede votar en ella.
+        pub creator_can_vote: bool,
+        /// Si el peso de los votos decae a medida que se acerca el plazo.
+        pub decay_mode: bool,
+        /// Si los empates se resuelven de forma pseudoaleatoria.
+        pub tie_break_random: bool,
+        /// Bloques de la fase de revelación de las votaciones secretas.
+        pub reveal_period: u32,
+        /// Primer bloque del período de registro de votantes.
+        pub registration_open_block: u32,
+        /// Bloque en el que se cierra el registro de votantes.
+        pub registration_close_block: u32,
+        /// Si los votos o la creación de propuestas están pausados, como `is_paused`.
+        pub paused: bool,
+        /// Si los votos están pausados.
+        pub voting_paused: bool,
+        /// Si la creación de propuestas está pausada.
+        pub creation_paused: bool,
+    }
+
+    impl Proposal {
+        /// Suma de todos los votos emitidos, incluidas las abstenciones y las opciones. Una papeleta
+        /// de aprobación cuenta una sola vez, aunque apruebe varias opciones.
+        pub fn total_votes(&self) -> u32 {
+            self.option_votes
+                .iter()
+                .fold(
+                    self.yes_votes
+                        .saturating_add(self.no_votes)
+                        .saturating_add(self.abstain_votes)
+                        .saturating_add(self.downvotes),
+                    |total, votes| total.saturating_add(*votes),
+                )
+                .saturating_sub(self.approval_overlap)
+        }
+
+        /// Indica si los votos emitidos alcanzan el quórum, convertido a unidades de los contadores.
+        pub fn quorum_met(&self) -> bool {
+            u64::from(self.total_votes()) >= self.in_units(self.quorum)
+        }
+
+        /// Indica si los votos a favor alcanzan el umbral y la fracción de aprobación.
+        /// Una propuesta vetada nunca los alcanza.
+        pub fn meets_pass_rule(&self) -> bool {
+            let yes = u64::from(self.yes_votes);
+            let decided = yes.saturating_add(u64::from(self.no_votes));
+            !self.vetoed
+                && yes >= self.in_units(self.threshold)
+                && yes.saturating_mul(u64::from(self.pass_denominator))
+                    >= decided.saturating_mul(u64::from(self.pass_numerator))
+        }
+
+        /// Convierte una cantidad de votos a unidades de los contadores.
+        pub fn in_units(&self, votes: u32) -> u64 {
+            u64::from(votes).saturating_mul(u64::from(self.vote_unit.max(1)))
+        }
+
+        /// Votos acumulados por una opción.
+        pub fn votes_for(&self, choice: VoteChoice) -> u32 {
+            match choice {
+                VoteChoice::Yes => self.yes_votes,
+                VoteChoice::No => self.no_votes,
+                VoteChoice::Abstain => self.abstain_votes,
+                VoteChoice::Downvote => self.downvotes,
+                VoteChoice::Option(index) => self.option_votes.get(index as usize).copied().unwrap_or(0),
+            }
+        }
+
+        /// Verifica que la opción elegida corresponda al tipo de propuesta.
+        pub fn validate_choice(&self, choice: VoteChoice) -> Result<(), Error> {
+            match choice {
+                VoteChoice::Option(index) if !self.options.is_empty() => {
+                    if index as usize >= self.options.len() {
+                        return Err(Error::InvalidOption);
+                    }
+                    Ok(())
+                }
+                VoteChoice::Option(_) => Err(Error::WrongVotingMode),
+                _ if !self.options.is_empty() => Err(Error::WrongVotingMode),
+                _ => Ok(()),
+            }
+        }
+
+        /// Suma `weight` votos al contador de la opción elegida.
+        pub fn add_votes(&mut self, choice: VoteChoice, weight: u32) {
+            match choice {
+                VoteChoice::Yes => self.yes_votes = self.yes_votes.saturating_add(weight),
+                VoteChoice::No => self.no_votes = self.no_votes.saturating_add(weight),
+                VoteChoice::Abstain => self.abstain_votes = self.abstain_votes.saturating_add(weight),
+                VoteChoice::Downvote => self.downvotes = self.downvotes.saturating_add(weight),
+                VoteChoice::Option(index) => {
+                    if let Some(votes) = self.option_votes.get_mut(index as usize) {
+                        *votes = votes.saturating_add(weight);
+                    }
+                }
+            }
+        }
+
+        /// Resta `weight` votos del contador de la opción elegida.
+        pub fn remove_votes(&mut self, choice: VoteChoice, weight: u32) {
+            match choice {
+                VoteChoice::Yes => self.yes_votes = self.yes_votes.saturating_sub(weight),
+                VoteChoice::No => self.no_votes = self.no_votes.saturating_sub(weight),
+                VoteChoice::Abstain => self.abstain_votes = self.abstain_votes.saturating_sub(weight),
+                VoteChoice::Downvote => self.downvotes = self.downvotes.saturating_sub(weight),
+                VoteChoice::Option(index) => {
+                    if let Some(votes) = self.option_votes.get_mut(index as usize) {
+                        *votes = votes.saturating_sub(weight);
+                    }
+                }
+            }
+        }
     }
 
 
@@ -45,7 +804,10 @@ mod voting_system {
     pub struct ProposalCreated {
         #[ink(topic)]
         pub id: u32,
+        #[ink(topic)]
+        pub creator: AccountId,
         pub title: String,
+        pub metadata_uri: String,
     }
     /// Se emite cada vez que un usuario registra un voto válido.
     #[ink(event)]
@@ -54,6 +816,215 @@ mod voting_system {
         pub proposal_id: u32,
         #[ink(topic)]
         pub voter: AccountId,
+        pub choice: VoteChoice,
+        /// Votos acumulados por la opción elegida, incluido este voto.
+        pub new_total: u32,
+        /// Peso efectivo con que se contó el voto.
+        pub weight: u32,
+        /// Marca de tiempo del bloque en que se emitió el voto.
+        pub timestamp: Timestamp,
+    }
+    /// Se emite cuando un usuario vota explicando su motivo.
+    #[ink(event)]
+    pub struct VoteCastWithReason {
+        #[ink(topic)]
+        pub proposal_id: u32,
+        #[ink(topic)]
+        pub voter: AccountId,
+        pub choice: VoteChoice,
+        pub reason: String,
+    }
+    /// Se emite cada vez que un usuario registra un voto negativo.
+    #[ink(event)]
+    pub struct DownvoteCast {
+        #[ink(topic)]
+        pub proposal_id: u32,
+        #[ink(topic)]
+        pub voter: AccountId,
+    }
+    /// Se emite cuando un usuario retira su voto.
+    #[ink(event)]
+    pub struct VoteRevoked {
+        #[ink(topic)]
+        pub proposal_id: u32,
+        #[ink(topic)]
+        pub voter: AccountId,
+    }
+    /// Se emite cuando un usuario cambia la opción de su voto.
+    #[ink(event)]
+    pub struct VoteChanged {
+        #[ink(topic)]
+        pub proposal_id: u32,
+        #[ink(topic)]
+        pub voter: AccountId,
+        pub old_choice: VoteChoice,
+        pub new_choice: VoteChoice,
+    }
+    /// Se emite cuando el owner cierra manualmente una propuesta.
+    #[ink(event)]
+    pub struct ProposalClosed {
+        #[ink(topic)]
+        pub id: u32,
+        pub total_votes: u32,
+    }
+    /// Se emite cuando se congela el resultado de una propuesta.
+    #[ink(event)]
+    pub struct ProposalFinalized {
+        #[ink(topic)]
+        pub id: u32,
+        pub final_votes: u32,
+        pub passed: bool,
+    }
+    /// Se emite cuando el owner extiende el plazo de votación de una propuesta.
+    #[ink(event)]
+    pub struct DeadlineExtended {
+        #[ink(topic)]
+        pub id: u32,
+        pub end_block: u32,
+    }
+    /// Se emite cuando el owner veta una propuesta aprobada.
+    #[ink(event)]
+    pub struct ProposalVetoed {
+        #[ink(topic)]
+        pub id: u32,
+    }
+    /// Se emite cuando el owner reabre una propuesta cerrada.
+    #[ink(event)]
+    pub struct ProposalReopened {
+        #[ink(topic)]
+        pub id: u32,
+        pub end_block: u32,
+    }
+    /// Se emite cuando el owner corrige el quórum y el umbral de una propuesta.
+    #[ink(event)]
+    pub struct ThresholdsUpdated {
+        #[ink(topic)]
+        pub id: u32,
+        pub quorum: u32,
+        pub threshold: u32,
+    }
+    /// Se emite cuando el creador corrige la descripción de una propuesta.
+    #[ink(event)]
+    pub struct ProposalAmended {
+        #[ink(topic)]
+        pub id: u32,
+        /// Cantidad de correcciones registradas, incluida esta.
+        pub edits: u32,
+    }
+    /// Se emite cuando una propuesta en borrador reúne los patrocinios necesarios.
+    #[ink(event)]
+    pub struct ProposalActivated {
+        #[ink(topic)]
+        pub id: u32,
+    }
+    /// Se emite cuando el creador cancela una propuesta.
+    #[ink(event)]
+    pub struct ProposalCancelled {
+        #[ink(topic)]
+        pub id: u32,
+    }
+    /// Se emite cuando el owner elimina una propuesta del storage.
+    #[ink(event)]
+    pub struct ProposalDeleted {
+        #[ink(topic)]
+        pub id: u32,
+    }
+    /// Se emite al desplegar el contrato, con el owner inicial.
+    #[ink(event)]
+    pub struct ContractDeployed {
+        #[ink(topic)]
+        pub owner: AccountId,
+        pub timestamp: Timestamp,
+    }
+    /// Se emite cuando el nuevo owner acepta la transferencia de propiedad.
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        pub previous: AccountId,
+        #[ink(topic)]
+        pub new: AccountId,
+    }
+
+    /// Se emite cuando una cuenta delega su voto o retira la delegación.
+    #[ink(event)]
+    pub struct DelegationChanged {
+        #[ink(topic)]
+        pub delegator: AccountId,
+        pub delegate: Option<AccountId>,
+        /// Último bloque en que la delegación aplica; 0 al retirarla.
+        pub until_block: u32,
+    }
+    /// Se emite cuando el owner pausa el contrato.
+    #[ink(event)]
+    pub struct Paused {
+        #[ink(topic)]
+        pub by: AccountId,
+    }
+    /// Se emite cuando el owner reanuda el contrato.
+    #[ink(event)]
+    pub struct Unpaused {
+        #[ink(topic)]
+        pub by: AccountId,
+    }
+    /// Se emite cuando el owner reemplaza el código del contrato.
+    #[ink(event)]
+    pub struct CodeUpgraded {
+        #[ink(topic)]
+        pub code_hash: Hash,
+    }
+    /// Se emite cuando el owner bloquea una cuenta.
+    #[ink(event)]
+    pub struct Blacklisted {
+        #[ink(topic)]
+        pub account: AccountId,
+    }
+    /// Se emite cuando el owner desbloquea una cuenta.
+    #[ink(event)]
+    pub struct Unblacklisted {
+        #[ink(topic)]
+        pub account: AccountId,
+    }
+    /// Se emite cuando el owner retira fondos de la tesorería.
+    #[ink(event)]
+    pub struct TreasuryWithdrawn {
+        #[ink(topic)]
+        pub to: AccountId,
+        pub amount: Balance,
+    }
+    /// Se emite cuando el owner designa un moderador.
+    #[ink(event)]
+    pub struct ModeratorAdded {
+        #[ink(topic)]
+        pub account: AccountId,
+    }
+    /// Se emite cuando el owner quita a un moderador.
+    #[ink(event)]
+    pub struct ModeratorRemoved {
+        #[ink(topic)]
+        pub account: AccountId,
+    }
+    /// Se emite cuando un owner propone una acción de gobierno.
+    #[ink(event)]
+    pub struct ActionProposed {
+        #[ink(topic)]
+        pub id: u32,
+        #[ink(topic)]
+        pub proposer: AccountId,
+    }
+    /// Se emite cuando un owner aprueba una acción de gobierno.
+    #[ink(event)]
+    pub struct ActionApproved {
+        #[ink(topic)]
+        pub id: u32,
+        #[ink(topic)]
+        pub approver: AccountId,
+        pub approvals: u32,
+    }
+    /// Se emite cuando se ejecuta una acción de gobierno aprobada.
+    #[ink(event)]
+    pub struct ActionExecuted {
+        #[ink(topic)]
+        pub id: u32,
     }
 
     /// STORAGE
@@ -62,12 +1033,148 @@ mod voting_system {
     pub struct VotingSystem {
         /// Mapa persistente de ID de propuesta a sus datos estructurales.
         proposals: Mapping<u32, Proposal>,
-        /// Registro de votantes para evitar duplicidad.
-        voters: Mapping<(u32, AccountId), bool>,
+        /// Registro de votantes y de la opción elegida, para evitar duplicidad.
+        voters: Mapping<(u32, AccountId), VoteChoice>,
+        /// Cuentas que votaron en cada propuesta, indexadas por posición, para consultarlas y limpiar
+        /// `voters` al eliminarla. Cada entrada ocupa su propia celda de storage.
+        proposal_voters: Mapping<(u32, u32), AccountId>,
+        /// Cantidad de votantes de cada propuesta, es decir, posiciones ocupadas en `proposal_voters`.
+        voter_counts: Mapping<u32, u32>,
+        /// Posición de cada votante en `proposal_voters`, para quitarlo sin recorrer la lista.
+        voter_positions: Mapping<(u32, AccountId), u32>,
         /// Contador incremental para asignar identificadores únicos a las propuestas.
         proposal_count: u32,
+        /// Cantidad de propuestas que existen actualmente, descontadas las eliminadas.
+        live_count: u32,
+        /// Cantidad de propuestas existentes en cada estado guardado.
+        status_counts: Mapping<ProposalStatus, u32>,
         /// Dirección de la cuenta que desplegó el contrato (owner).
         owner: AccountId,
+        /// Cuenta propuesta como nuevo owner, pendiente de aceptar.
+        pending_owner: Option<AccountId>,
+        /// Peso con el que cada votante participa en cada propuesta.
+        vote_weights: Mapping<(u32, AccountId), u32>,
+        /// Valor transferido por cada votante en modo ponderado, para poder reembolsarlo.
+        contributions: Mapping<(u32, AccountId), Balance>,
+        /// Cuenta en la que cada votante delegó su voto y último bloque en que la delegación aplica.
+        /// Las vencidas pueden seguir guardadas, pero se ignoran.
+        delegations: Mapping<AccountId, (AccountId, u32)>,
+        /// Cuentas que delegaron directamente en cada delegado, indexadas por posición.
+        delegators: Mapping<(AccountId, u32), AccountId>,
+        /// Cantidad de cuentas que delegaron directamente en cada delegado.
+        delegator_counts: Mapping<AccountId, u32>,
+        /// Posición de cada delegante en la lista de su delegado.
+        delegator_positions: Mapping<AccountId, u32>,
+        /// Delegado cuyo voto en cada propuesta ya sumó el peso de cada delegante. Mientras ese voto
+        /// siga en pie, el delegante no puede votar directamente ni volver a sumar en otro voto.
+        carried_by: Mapping<(u32, AccountId), AccountId>,
+        /// Delegantes cuyo peso sumó cada delegado en cada propuesta, indexados por posición, para
+        /// liberarlos si el voto se retira.
+        carried_delegators: Mapping<CarriedKey, AccountId>,
+        /// Cantidad de delegantes cuyo peso sumó cada delegado en cada propuesta.
+        carried_counts: Mapping<(u32, AccountId), u32>,
+        /// Compromisos de voto secreto pendientes de revelar.
+        commitments: Mapping<(u32, AccountId), Commitment>,
+        /// Cuentas que registraron un compromiso en cada propuesta, indexadas por posición, para
+        /// limpiar `commitments` al eliminarla.
+        committers: Mapping<(u32, u32), AccountId>,
+        /// Cantidad de cuentas registradas en `committers` para cada propuesta.
+        commit_counts: Mapping<u32, u32>,
+        /// Configuración fijada al desplegar el contrato.
+        settings: Settings,
+        /// Cuentas habilitadas para votar cuando `settings.allowlist_enabled` está activo.
+        allowlist: Mapping<AccountId, bool>,
+        /// Cantidad de cuentas en `allowlist`, usada como padrón para calcular la participación.
+        registered_voters: u32,
+        /// Primer bloque en el que las cuentas pueden registrarse por su cuenta.
+        registration_open_block: u32,
+        /// Bloque a partir del cual se cierra el registro.
+        registration_close_block: u32,
+        /// Títulos en uso, mantenidos solo si `settings.dedupe_titles` está activo.
+        titles: Mapping<String, bool>,
+        /// Interruptor de emergencia que bloquea la emisión y modificación de votos.
+        voting_paused: bool,
+        /// Interruptor de emergencia que bloquea la creación de propuestas.
+        creation_paused: bool,
+        /// Votos registrados en las propuestas existentes.
+        total_votes_cast: u32,
+        /// Cuentas que votaron alguna vez en cualquier propuesta.
+        participants: Mapping<AccountId, bool>,
+        /// Cantidad de cuentas distintas que votaron alguna vez. No disminuye al eliminar propuestas.
+        unique_voters: u32,
+        /// Cerrojo contra reentradas, tomado mientras se ejecuta un mensaje protegido. Vive en su
+        /// propia celda para que se escriba en el storage antes de cualquier llamada externa.
+        locked: Lazy<bool>,
+        /// Bloque del último voto de cada cuenta, para aplicar `settings.vote_cooldown`.
+        last_vote_block: Mapping<AccountId, u32>,
+        /// Créditos de voto cuadrático disponibles por cuenta.
+        credits: Mapping<AccountId, u64>,
+        /// Créditos gastados por cada votante en cada propuesta, para poder reembolsarlos.
+        spent_credits: Mapping<(u32, AccountId), u64>,
+        /// Reputación asignada por el owner a cada cuenta; 0 si no tiene.
+        reputation: Mapping<AccountId, u32>,
+        /// Peso de voto asignado por el owner a cada cuenta; sin entrada vale 1.
+        account_weights: Mapping<AccountId, u32>,
+        /// Saldo del token de gobernanza de cada votante en el bloque de instantánea de cada
+        /// propuesta, guardado para no repetir la llamada externa.
+        token_balances: Mapping<(u32, AccountId), Balance>,
+        /// Cuentas que pueden cerrar y eliminar propuestas sin ser owner.
+        moderators: Mapping<AccountId, bool>,
+        /// Moderadores actuales indexados por posición, para poder listarlos.
+        moderator_list: Mapping<u32, AccountId>,
+        /// Posición de cada moderador en `moderator_list`, para quitarlo sin recorrer la lista.
+        moderator_positions: Mapping<AccountId, u32>,
+        /// Cantidad de moderadores actuales.
+        moderator_count: u32,
+        /// Owners del contrato en orden, con la marca de tiempo en que asumió cada uno. Empieza
+        /// con quien lo desplegó y termina con la cuenta nula si se renunció a la propiedad.
+        /// Se indexa por posición.
+        owner_history: Mapping<u32, (AccountId, Timestamp)>,
+        /// Cantidad de entradas de `owner_history`.
+        owner_history_len: u32,
+        /// Votos a favor de cada propuesta registrados en cada instantánea, por (bloque, ID).
+        snapshots: Mapping<(u32, u32), u32>,
+        /// Bloques en los que se tomaron instantáneas, indexados por orden de toma.
+        snapshot_blocks: Mapping<u32, u32>,
+        /// Cantidad de bloques registrados en `snapshot_blocks`.
+        snapshot_count: u32,
+        /// Nonce de cada cuenta, que crece con cada acción que modifica su participación y que los
+        /// votos firmados deben incluir. Una actualización de código debe conservar este campo con
+        /// el mismo nombre y en el mismo contrato: la clave de storage del `Mapping` se deriva de
+        /// ellos, y si cambia los nonces vuelven a 0 y las firmas viejas se podrían reutilizar.
+        nonces: Mapping<AccountId, u64>,
+        /// Motivo opcional con el que cada votante justificó su voto.
+        vote_reasons: Mapping<(u32, AccountId), String>,
+        /// Descripciones anteriores de cada propuesta, indexadas de la más vieja a la más reciente.
+        edit_history: Mapping<(u32, u32), String>,
+        /// Cantidad de descripciones anteriores guardadas de cada propuesta.
+        edit_counts: Mapping<u32, u32>,
+        /// Propuestas en las que votó cada cuenta, indexadas por posición en el orden en que votó.
+        voting_history: Mapping<(AccountId, u32), u32>,
+        /// Cantidad de entradas del historial de votos de cada cuenta.
+        history_counts: Mapping<AccountId, u32>,
+        /// Posición de cada propuesta en el historial de una cuenta.
+        history_positions: Mapping<(AccountId, u32), u32>,
+        /// Cuentas que patrocinaron cada propuesta.
+        sponsors: Mapping<(u32, AccountId), bool>,
+        /// Patrocinadores de cada propuesta, indexados por posición hasta su `sponsor_count`.
+        sponsor_list: Mapping<(u32, u32), AccountId>,
+        /// Orden de preferencia de cada votante en propuestas de opción múltiple, si votó por ranking.
+        rankings: Mapping<(u32, AccountId), Ranking>,
+        /// Opciones aprobadas por cada votante en propuestas de opción múltiple, si votó por aprobación.
+        approvals: Mapping<(u32, AccountId), Approval>,
+        /// Puntos repartidos por cada votante en propuestas de voto acumulativo.
+        allocations: Mapping<(u32, AccountId), Allocation>,
+        /// Cuentas bloqueadas, que no pueden votar ni crear propuestas.
+        blacklisted: Mapping<AccountId, bool>,
+        /// Fondos reembolsables retenidos: depósitos pendientes y aportes de votos ponderados.
+        locked_funds: Balance,
+        /// Acciones de gobierno propuestas por los owners, por ID.
+        pending_actions: Mapping<u32, PendingAction>,
+        /// Contador incremental para asignar IDs a las acciones de gobierno.
+        action_count: u32,
+        /// Owners que aprobaron cada acción de gobierno.
+        action_approvals: Mapping<(u32, AccountId), bool>,
     }
 
   
@@ -77,141 +1184,6157 @@ mod voting_system {
         /// Constructor
         #[ink(constructor)]
         pub fn new() -> Self {
-            Self {
+            Self::new_with_settings(Settings::default())
+        }
+
+        /// Constructor en modo ponderado: cada voto pesa lo que se transfiere junto a él.
+        #[ink(constructor)]
+        pub fn new_value_weighted() -> Self {
+            Self::new_with_settings(Settings {
+                value_weighted: true,
+                ..Default::default()
+            })
+        }
+
+        /// Constructor con una longitud máxima de título personalizada, en bytes.
+        #[ink(constructor)]
+        pub fn new_with_limits(max_title_len: u32) -> Self {
+            Self::new_with_settings(Settings {
+                max_title_len,
+                ..Default::default()
+            })
+        }
+
+        /// Constructor con la configuración completa del contrato.
+        #[ink(constructor)]
+        pub fn new_with_settings(mut settings: Settings) -> Self {
+            let owner = Self::env().caller();
+            let mut owners = Vec::new();
+            for account in core::mem::take(&mut settings.owners) {
+                if !owners.contains(&account) {
+                    owners.push(account);
+                }
+            }
+            if owners.is_empty() {
+                owners.push(owner);
+            }
+            settings.required_approvals = settings.required_approvals.clamp(1, owners.len() as u32);
+            settings.owners = owners;
+            settings.reveal_period = settings.reveal_period.max(1);
+            Self::env().emit_event(ContractDeployed {
+                owner,
+                timestamp: Self::env().block_timestamp(),
+            });
+            let mut contract = Self {
                 proposals: Mapping::default(),
                 voters: Mapping::default(),
+                proposal_voters: Mapping::default(),
+                voter_counts: Mapping::default(),
+                voter_positions: Mapping::default(),
                 proposal_count: 0,
-                owner: Self::env().caller(),
-            }
+                live_count: 0,
+                status_counts: Mapping::default(),
+                owner,
+                pending_owner: None,
+                vote_weights: Mapping::default(),
+                contributions: Mapping::default(),
+                delegations: Mapping::default(),
+                delegators: Mapping::default(),
+                delegator_counts: Mapping::default(),
+                delegator_positions: Mapping::default(),
+                carried_by: Mapping::default(),
+                carried_delegators: Mapping::default(),
+                carried_counts: Mapping::default(),
+                commitments: Mapping::default(),
+                committers: Mapping::default(),
+                commit_counts: Mapping::default(),
+                settings,
+                allowlist: Mapping::default(),
+                registered_voters: 0,
+                registration_open_block: 0,
+                registration_close_block: 0,
+                titles: Mapping::default(),
+                voting_paused: false,
+                creation_paused: false,
+                total_votes_cast: 0,
+                participants: Mapping::default(),
+                unique_voters: 0,
+                locked: Lazy::new(),
+                last_vote_block: Mapping::default(),
+                credits: Mapping::default(),
+                spent_credits: Mapping::default(),
+                reputation: Mapping::default(),
+                account_weights: Mapping::default(),
+                token_balances: Mapping::default(),
+                moderators: Mapping::default(),
+                moderator_list: Mapping::default(),
+                moderator_positions: Mapping::default(),
+                moderator_count: 0,
+                owner_history: Mapping::default(),
+                owner_history_len: 1,
+                snapshots: Mapping::default(),
+                snapshot_blocks: Mapping::default(),
+                snapshot_count: 0,
+                nonces: Mapping::default(),
+                vote_reasons: Mapping::default(),
+                edit_history: Mapping::default(),
+                edit_counts: Mapping::default(),
+                voting_history: Mapping::default(),
+                history_counts: Mapping::default(),
+                history_positions: Mapping::default(),
+                sponsors: Mapping::default(),
+                sponsor_list: Mapping::default(),
+                rankings: Mapping::default(),
+                approvals: Mapping::default(),
+                allocations: Mapping::default(),
+                blacklisted: Mapping::default(),
+                locked_funds: 0,
+                pending_actions: Mapping::default(),
+                action_count: 0,
+                action_approvals: Mapping::default(),
+            };
+            contract.owner_history.insert(0, &(owner, Self::env().block_timestamp()));
+            contract
         }
 
         /// Crea una nueva propuesta. Solo accesible por el administrador.
+        /// La votación queda abierta durante `params.duration_blocks` bloques desde el actual.
+        /// Debe transferirse al menos el depósito configurado; el excedente no se reembolsa.
         /// Retorna el ID de la propuesta creada o un error de permisos.
-        #[ink(message)]
-        pub fn create_proposal(&mut self, title: String) -> Result<u32, Error> {
-            //Valida que el caller sea el owner
-            if self.env().caller() != self.owner {
-                return Err(Error::OnlyOwnerCanPerformAction);
+        /// La propuesta queda sin descripción; para indicarla se usa `create_proposal_with_description`.
+        #[ink(message, payable)]
+        pub fn create_proposal(&mut self, title: String, params: ProposalParams) -> Result<u32, Error> {
+            self.create_proposal_with_description(title, String::new(), params)
+        }
+
+        /// Igual que `create_proposal`, guardando además una descripción detallada de hasta
+        /// `MAX_DESCRIPTION_LEN` bytes, separada del título.
+        #[ink(message, payable)]
+        pub fn create_proposal_with_description(
+            &mut self,
+            title: String,
+            description: String,
+            params: ProposalParams,
+        ) -> Result<u32, Error> {
+            self.non_reentrant(|this| {
+                //Valida que el caller sea el owner
+                this.ensure_owner()?;
+                this.create_checked(title, description, params)
+            })
+        }
+
+        /// Valida y almacena una propuesta, una vez verificados los permisos del caller.
+        fn create_checked(&mut self, title: String, description: String, params: ProposalParams) -> Result<u32, Error> {
+            self.ensure_creation_not_paused()?;
+            self.ensure_not_blacklisted(self.env().caller())?;
+            self.validate_title(&title)?;
+            if description.len() > MAX_DESCRIPTION_LEN as usize {
+                return Err(Error::DescriptionTooLong);
+            }
+            self.validate_params(&params)?;
+            self.ensure_capacity(1)?;
+            self.ensure_deposit(1)?;
+
+            Ok(self.store_proposal(title, description, params))
+        }
+
+        /// Crea varias propuestas con los mismos parámetros en una sola transacción.
+        /// Solo accesible por el administrador. Debe transferirse un depósito por propuesta.
+        /// Retorna los IDs asignados, en orden.
+        #[ink(message, payable)]
+        pub fn create_proposals(
+            &mut self,
+            titles: Vec<String>,
+            params: ProposalParams,
+        ) -> Result<Vec<u32>, Error> {
+            self.non_reentrant(|this| {
+                this.ensure_owner()?;
+                this.create_batch_checked(titles, params)
+            })
+        }
+
+        /// Valida y almacena un lote de propuestas, una vez verificados los permisos del caller.
+        fn create_batch_checked(&mut self, titles: Vec<String>, params: ProposalParams) -> Result<Vec<u32>, Error> {
+            self.ensure_creation_not_paused()?;
+            self.ensure_not_blacklisted(self.env().caller())?;
+            self.validate_params(&params)?;
+            if titles.is_empty() {
+                return Err(Error::EmptyProposalSet);
             }
+            if titles.len() > MAX_BATCH_SIZE as usize {
+                return Err(Error::BatchTooLarge);
+            }
+            for (index, title) in titles.iter().enumerate() {
+                self.validate_title(title)?;
+                if self.settings.dedupe_titles && titles[..index].contains(title) {
+                    return Err(Error::DuplicateTitle);
+                }
+            }
+            self.ensure_capacity(titles.len() as u32)?;
+            self.ensure_deposit(titles.len() as u32)?;
+
+            Ok(titles
+                .into_iter()
+                .map(|title| self.store_proposal(title, String::new(), params.clone()))
+                .collect())
+        }
+
+        /// Almacena una nueva propuesta activa y emite su evento. Retorna el ID asignado.
+        fn store_proposal(&mut self, title: String, description: String, params: ProposalParams) -> u32 {
             //Asignar ID
             let id = self.proposal_count;
             // crear la propuesta
+            let end_block = self.env().block_number().saturating_add(params.duration_blocks);
             let proposal = Proposal {
-                description: title.clone(),
-                votes: 0,
+                title: title.clone(),
+                description,
+                yes_votes: 0,
+                no_votes: 0,
+                abstain_votes: 0,
+                downvotes: 0,
+                end_block,
+                quorum: params.quorum,
+                threshold: params.threshold,
+                status: if self.settings.min_sponsors > 0 {
+                    ProposalStatus::Draft
+                } else {
+                    ProposalStatus::Active
+                },
+                tags: params.tags,
+                reveal_block: params.commit_reveal.then_some(end_block),
+                deposit: self.settings.proposal_deposit,
+                creator: self.env().caller(),
+                created_at: self.env().block_timestamp(),
+                option_votes: ink::prelude::vec![0; params.options.len()],
+                options: params.options,
+                metadata_uri: params.metadata_uri,
+                action: params.action,
+                start_block: params.start_block.max(self.env().block_number()),
+                min_turnout: params.min_turnout,
+                pass_numerator: if params.pass_denominator == 0 { 1 } else { params.pass_numerator },
+                pass_denominator: if params.pass_denominator == 0 { 2 } else { params.pass_denominator },
+                sponsor_count: 0,
+                final_votes: 0,
+                passed: false,
+                executed: false,
+                eligibility_root: None,
+                depends_on: params.depends_on,
+                point_budget: params.point_budget,
+                max_weight_per_voter: params.max_weight_per_voter,
+                vetoed: false,
+                auto_close_on_quorum: params.auto_close_on_quorum,
+                min_reputation: params.min_reputation,
+                snapshot_block: self.env().block_number(),
+                vote_unit: if self.settings.decay_mode { DECAY_SCALE as u32 } else { 1 },
+                approval_overlap: 0,
             };
             // Almacenar la propuesta
             self.proposals.insert(id, &proposal);
-            self.proposal_count = self.proposal_count.saturating_add(1);
+            self.locked_funds = self.locked_funds.saturating_add(proposal.deposit);
+            if self.settings.dedupe_titles {
+                self.titles.insert(&proposal.title, &true);
+            }
+            // `ensure_capacity` ya verificó que el contador no desborda
+            self.proposal_count = id + 1;
+            self.live_count = self.live_count.saturating_add(1);
+            let count = self.status_count(proposal.status).saturating_add(1);
+            self.status_counts.insert(proposal.status, &count);
             //Emite el evento
-            self.env().emit_event(ProposalCreated { id, title });
+            self.env().emit_event(ProposalCreated {
+                id,
+                creator: proposal.creator,
+                title,
+                metadata_uri: proposal.metadata_uri,
+            });
         
-            Ok(id)
+            id
         }
 
-        /// Registra un voto para una propuesta específica. 
+        /// Registra un voto para una propuesta específica: a favor, en contra o abstención en
+        /// propuestas de sí/no, o `VoteChoice::Option` en propuestas de opción múltiple.
         /// Valida la existencia de la propuesta y que el usuario no haya votado previamente.
-        #[ink(message)]
-        pub fn vote(&mut self, proposal_id: u32) -> Result<(), Error> {
-            let caller = self.env().caller();
-
-           // Verificar existencia 
-            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+        /// En modo ponderado el voto suma el valor transferido; si no, suma el peso asignado a la
+        /// cuenta con `set_weight` (1 por defecto). A eso se agrega el peso asignado de cada cuenta
+        /// que delegó en el caller y no votó por su cuenta, calculado al momento de votar.
+        #[ink(message, payable)]
+        pub fn vote(&mut self, proposal_id: u32, choice: VoteChoice) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_voting_not_paused()?;
+                let caller = this.env().caller();
+                let contribution = if this.settings.value_weighted {
+                    this.env().transferred_value()
+                } else {
+                    0
+                };
+                this.cast_vote(proposal_id, caller, choice, contribution, None)
+            })
+        }
 
-            // Verificar que no haya votado antes 
-            if self.voters.get((proposal_id, caller)).unwrap_or(false) {
-                return Err(Error::AlreadyVoted);
+        /// Simula un voto directo de `account` sin modificar el storage: aplica las mismas
+        /// verificaciones que `vote` (pausa, existencia, modo de votación, plazo, bloqueo,
+        /// habilitación, voto previo, delegación, saldo de token, espera y peso asignado) y
+        /// retorna el primer error, u `Ok(())` si el voto se registraría. No valida la opción
+        /// elegida, y en propuestas con raíz de elegibilidad retorna `InvalidProof` porque la
+        /// prueba se envía recién con `vote_with_proof`.
+        #[ink(message)]
+        pub fn can_vote(&self, proposal_id: u32, account: AccountId) -> Result<(), Error> {
+            self.ensure_voting_not_paused()?;
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            if proposal.reveal_block.is_some() {
+                return Err(Error::WrongVotingMode);
+            }
+            self.check_can_vote(proposal_id, &proposal, account, None)?;
+            self.token_balance(proposal_id, proposal.snapshot_block, account)?;
+            self.ensure_cooldown(account)?;
+            if !self.settings.value_weighted {
+                self.ensure_weight(account)?;
             }
+            Ok(())
+        }
 
-            // Registrar voto
-            proposal.votes = proposal.votes.saturating_add(1);
-            self.proposals.insert(proposal_id, &proposal);
-            self.voters.insert((proposal_id, caller), &true);
+        /// Vota a favor en varias propuestas en una sola transacción, hasta `MAX_BATCH_SIZE`. Cada
+        /// voto se intenta por separado y se retorna su resultado en el mismo orden, por lo que un
+        /// fallo (por ejemplo `AlreadyVoted`) no impide los demás. Con `settings.vote_cooldown`
+        /// solo el primer voto del lote respeta la espera. No admite el modo ponderado, ya que el
+        /// valor transferido no puede repartirse entre las propuestas.
+        #[ink(message)]
+        pub fn vote_many(&mut self, proposal_ids: Vec<u32>) -> Result<Vec<Result<(), Error>>, Error> {
+            self.non_reentrant(|this| {
+                this.ensure_voting_not_paused()?;
+                if this.settings.value_weighted {
+                    return Err(Error::WrongVotingMode);
+                }
+                if proposal_ids.len() > MAX_BATCH_SIZE as usize {
+                    return Err(Error::BatchTooLarge);
+                }
+                let caller = this.env().caller();
+                Ok(proposal_ids
+                    .into_iter()
+                    .map(|id| this.cast_vote(id, caller, VoteChoice::Yes, 0, None))
+                    .collect())
+            })
+        }
 
-            // Emitir evento 
-            self.env().emit_event(VoteCast { proposal_id, voter: caller });
+        /// Igual que `vote`, para propuestas con raíz de elegibilidad: `proof` es la lista de
+        /// hashes hermanos desde la hoja del caller hasta la raíz de Merkle de la propuesta.
+        #[ink(message, payable)]
+        pub fn vote_with_proof(
+            &mut self,
+            proposal_id: u32,
+            choice: VoteChoice,
+            proof: Vec<[u8; 32]>,
+        ) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_voting_not_paused()?;
+                let caller = this.env().caller();
+                let contribution = if this.settings.value_weighted {
+                    this.env().transferred_value()
+                } else {
+                    0
+                };
+                this.cast_vote(proposal_id, caller, choice, contribution, Some(&proof))
+            })
+        }
 
+        /// Igual que `vote`, pero guarda y publica el motivo del voto. El motivo comparte
+        /// el límite de longitud del título.
+        #[ink(message, payable)]
+        pub fn vote_with_reason(
+            &mut self,
+            proposal_id: u32,
+            choice: VoteChoice,
+            reason: String,
+        ) -> Result<(), Error> {
+            if reason.len() > self.settings.max_title_len as usize {
+                return Err(Error::ReasonTooLong);
+            }
+            self.vote(proposal_id, choice)?;
+            let caller = self.env().caller();
+            self.vote_reasons.insert((proposal_id, caller), &reason);
+            self.env().emit_event(VoteCastWithReason {
+                proposal_id,
+                voter: caller,
+                choice,
+                reason,
+            });
             Ok(())
         }
 
-        /// Consulta pública de los datos de una propuesta.
+        /// Retorna el motivo con el que votó una cuenta, o `None` si votó sin motivo o no votó.
         #[ink(message)]
-        pub fn get_proposal(&self, proposal_id: u32) -> Result<(String, u32), Error> {
-            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
-            Ok((proposal.description, proposal.votes))
+        pub fn get_vote_reason(&self, proposal_id: u32, account: AccountId) -> Result<Option<String>, Error> {
+            if !self.proposals.contains(proposal_id) {
+                return Err(Error::ProposalDoesNotExist);
+            }
+            Ok(self.vote_reasons.get((proposal_id, account)))
         }
 
-        /// Retorna el número total de propuestas registradas en el sistema.
+        /// Registra un voto por ranking en una propuesta de opción múltiple: `ranking` lista los
+        /// índices de las opciones de la más a la menos preferida, sin repetir. El voto cuenta
+        /// para la primera preferencia y el ranking completo se usa en `tally_irv`.
+        #[ink(message, payable)]
+        pub fn vote_ranked(&mut self, proposal_id: u32, ranking: Ranking) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_voting_not_paused()?;
+                let first = *ranking.first().ok_or(Error::InvalidOption)?;
+                for (position, index) in ranking.iter().enumerate() {
+                    if ranking[..position].contains(index) {
+                        return Err(Error::InvalidOption);
+                    }
+                }
+                let proposal = this.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+                for index in &ranking {
+                    proposal.validate_choice(VoteChoice::Option(*index))?;
+                }
+
+                let caller = this.env().caller();
+                let contribution = if this.settings.value_weighted {
+                    this.env().transferred_value()
+                } else {
+                    0
+                };
+                this.cast_vote(proposal_id, caller, VoteChoice::Option(first), contribution, None)?;
+                this.rankings.insert((proposal_id, caller), &ranking);
+                Ok(())
+            })
+        }
+
+        /// Registra un voto por aprobación en una propuesta de opción múltiple: el caller suma su
+        /// peso a cada opción de `options`, una sola vez por opción. Cada cuenta emite una única
+        /// papeleta, que en la participación total y el quórum cuenta una sola vez con su peso.
+        #[ink(message)]
+        pub fn approve(&mut self, proposal_id: u32, options: Approval) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_voting_not_paused()?;
+                let first = *options.first().ok_or(Error::InvalidOption)?;
+                for (position, index) in options.iter().enumerate() {
+                    if options[..position].contains(index) {
+                        return Err(Error::DuplicateOption);
+                    }
+                }
+                let proposal = this.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+                for index in &options {
+                    proposal.validate_choice(VoteChoice::Option(*index))?;
+                }
+
+                // El primer índice registra la papeleta; el resto suma el mismo peso
+                let caller = this.env().caller();
+                this.cast_vote(proposal_id, caller, VoteChoice::Option(first), 0, None)?;
+                let mut proposal = this.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+                let weight = this.vote_weights.get((proposal_id, caller)).unwrap_or(0);
+                for index in &options[1..] {
+                    proposal.add_votes(VoteChoice::Option(*index), weight);
+                    proposal.approval_overlap = proposal.approval_overlap.saturating_add(weight);
+                }
+                this.proposals.insert(proposal_id, &proposal);
+                this.approvals.insert((proposal_id, caller), &options);
+                Ok(())
+            })
+        }
+
+        /// Registra un voto acumulativo: el caller reparte hasta `point_budget` puntos entre las
+        /// opciones de la propuesta, como pares de índice y puntos. Los puntos no se ponderan ni
+        /// suman delegaciones. Cada cuenta emite una única papeleta.
+        #[ink(message)]
+        pub fn vote_cumulative(&mut self, proposal_id: u32, allocations: Allocation) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_voting_not_paused()?;
+                let mut proposal = this.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+                if proposal.point_budget == 0 || proposal.reveal_block.is_some() {
+                    return Err(Error::WrongVotingMode);
+                }
+                let (first, first_points) = *allocations.first().ok_or(Error::InvalidOption)?;
+                let mut total: u64 = 0;
+                for (position, (index, points)) in allocations.iter().enumerate() {
+                    proposal.validate_choice(VoteChoice::Option(*index))?;
+                    if allocations[..position].iter().any(|(previous, _)| previous == index) {
+                        return Err(Error::DuplicateOption);
+                    }
+                    total = total.saturating_add(u64::from(*points));
+                }
+                if total > u64::from(proposal.point_budget) {
+                    return Err(Error::BudgetExceeded);
+                }
+                let caller = this.env().caller();
+                this.ensure_can_vote(proposal_id, &proposal, caller, None)?;
+                this.ensure_cooldown(caller)?;
+
+                // La primera asignación registra la papeleta y el resto suma sus puntos
+                this.tally_vote(proposal_id, &mut proposal, caller, VoteChoice::Option(first), first_points);
+                for (index, points) in &allocations[1..] {
+                    let choice = VoteChoice::Option(*index);
+                    proposal.add_votes(choice, *points);
+                    this.env().emit_event(VoteCast {
+                        proposal_id,
+                        voter: caller,
+                        choice,
+                        new_total: proposal.votes_for(choice),
+                        weight: *points,
+                        timestamp: this.env().block_timestamp(),
+                    });
+                }
+                this.proposals.insert(proposal_id, &proposal);
+                this.close_on_quorum(proposal_id, &mut proposal);
+                this.allocations.insert((proposal_id, caller), &allocations);
+                let block = this.env().block_number();
+                this.last_vote_block.insert(caller, &block);
+                Ok(())
+            })
+        }
+
+        /// Registra el voto de `voter` enviado por un tercero (relayer), que paga el gas.
+        /// `signature` es la firma sr25519 de `voter` sobre la codificación SCALE de
+        /// `(VOTE_SIG_CONTEXT, contrato, proposal_id, voter, nonce, choice)`, donde `contrato` es la
+        /// dirección de este contrato, de modo que la firma no sirve en otro despliegue. `nonce` debe
+        /// coincidir con `get_nonce(voter)`; se incrementa en cada voto aceptado para impedir que la
+        /// firma se reutilice.
+        /// En modo ponderado el voto no aporta valor, ya que lo transferido pertenece al relayer.
+        #[ink(message)]
+        pub fn vote_with_sig(
+            &mut self,
+            proposal_id: u32,
+            voter: AccountId,
+            nonce: u64,
+            choice: VoteChoice,
+            signature: [u8; 64],
+        ) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_voting_not_paused()?;
+                if nonce != this.get_nonce(voter) {
+                    return Err(Error::BadNonce);
+                }
+                let message = (VOTE_SIG_CONTEXT, this.env().account_id(), proposal_id, voter, nonce, choice).encode();
+                let public_key: &[u8; 32] = voter.as_ref();
+                this.env()
+                    .sr25519_verify(&signature, &message, public_key)
+                    .map_err(|_| Error::InvalidSignature)?;
+
+                // El voto registrado incrementa el nonce e invalida la firma
+                this.cast_vote(proposal_id, voter, choice, 0, None)
+            })
+        }
+
+        /// Próximo nonce que debe firmar una cuenta para votar mediante `vote_with_sig`.
+        /// Equivalente a `nonce_of`.
+        #[ink(message)]
+        pub fn get_nonce(&self, account: AccountId) -> u64 {
+            self.nonce_of(account)
+        }
+
+        /// Nonce actual de una cuenta. Crece con cada voto, revocación, cambio de voto, compromiso,
+        /// registro y delegación de esa cuenta.
+        #[ink(message)]
+        pub fn nonce_of(&self, account: AccountId) -> u64 {
+            self.nonces.get(account).unwrap_or(0)
+        }
+
+        /// Voto cuadrático a favor: pide `weight` votos y cuesta el cuadrado de los votos que
+        /// efectivamente suma, tras aplicar el decaimiento y el tope por votante (redondeado hacia
+        /// arriba en modo de decaimiento). No suma peso delegado, que no pagaría créditos.
+        /// Los créditos gastados se devuelven si el voto se retira.
+        #[ink(message)]
+        pub fn vote_with_weight(&mut self, proposal_id: u32, weight: u32) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_voting_not_paused()?;
+                if weight == 0 {
+                    return Err(Error::ZeroWeight);
+                }
+                let caller = this.env().caller();
+                let mut proposal = this.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+                if proposal.reveal_block.is_some() {
+                    return Err(Error::WrongVotingMode);
+                }
+                proposal.validate_choice(VoteChoice::Yes)?;
+                this.ensure_can_vote(proposal_id, &proposal, caller, None)?;
+                this.ensure_cooldown(caller)?;
+
+                // Cobrar el costo cuadrático del peso aplicado antes de registrar el voto
+                let applied = this.applied_weight(&proposal, weight);
+                let unit = u64::from(proposal.vote_unit.max(1));
+                let cost = u64::from(applied)
+                    .saturating_mul(u64::from(applied))
+                    .div_ceil(unit.saturating_mul(unit));
+                let available = this.credits_of(caller);
+                if available < cost {
+                    return Err(Error::InsufficientCredits);
+                }
+                this.tally_vote(proposal_id, &mut proposal, caller, VoteChoice::Yes, applied);
+                this.close_on_quorum(proposal_id, &mut proposal);
+                this.credits.insert(caller, &(available - cost));
+                this.spent_credits.insert((proposal_id, caller), &cost);
+                let block = this.env().block_number();
+                this.last_vote_block.insert(caller, &block);
+
+                Ok(())
+            })
+        }
+
+        /// Registra un voto negativo. Equivale a `vote` con `VoteChoice::Downvote`: cada cuenta
+        /// puede votar a favor o en contra una sola vez por propuesta.
+        #[ink(message, payable)]
+        pub fn downvote(&mut self, proposal_id: u32) -> Result<(), Error> {
+            self.vote(proposal_id, VoteChoice::Downvote)
+        }
+
+        /// Registra el compromiso de un voto secreto: `commitment` debe ser el hash Blake2x256
+        /// de la codificación SCALE de `(choice, salt)`. Solo se acepta durante el plazo de votación
+        /// de propuestas creadas con `commit_reveal`.
+        #[ink(message)]
+        pub fn commit_vote(&mut self, proposal_id: u32, commitment: Commitment) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_voting_not_paused()?;
+                let caller = this.env().caller();
+                let proposal = this.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+                if proposal.reveal_block.is_none() {
+                    return Err(Error::WrongVotingMode);
+                }
+                this.ensure_can_vote(proposal_id, &proposal, caller, None)?;
+                if this.commitments.contains((proposal_id, caller)) {
+                    return Err(Error::AlreadyCommitted);
+                }
+
+                this.commitments.insert((proposal_id, caller), &commitment);
+                let position = this.commit_counts.get(proposal_id).unwrap_or(0);
+                this.committers.insert((proposal_id, position), &caller);
+                this.commit_counts.insert(proposal_id, &position.saturating_add(1));
+                this.bump_nonce(caller);
+                Ok(())
+            })
+        }
+
+        /// Revela un voto secreto y lo suma al conteo si coincide con el compromiso registrado.
+        /// Solo se acepta a partir del `reveal_block` de la propuesta y durante
+        /// `settings.reveal_period` bloques, aunque la votación se haya cerrado antes.
+        /// Los votos secretos suman 1 más el peso delegado, sin ponderar por valor.
+        #[ink(message)]
+        pub fn reveal_vote(
+            &mut self,
+            proposal_id: u32,
+            choice: VoteChoice,
+            salt: [u8; 32],
+        ) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_voting_not_paused()?;
+                let caller = this.env().caller();
+                let mut proposal = this.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+                let reveal_block = proposal.reveal_block.ok_or(Error::WrongVotingMode)?;
+                match proposal.status {
+                    ProposalStatus::Active | ProposalStatus::Closed => {}
+                    ProposalStatus::Cancelled => return Err(Error::ProposalCancelled),
+                    ProposalStatus::Finalized => return Err(Error::AlreadyFinalized),
+                    _ => return Err(Error::ProposalNotActive),
+                }
+                if this.env().block_number() < reveal_block {
+                    return Err(Error::RevealNotStarted);
+                }
+                if !this.reveal_pending(&proposal) {
+                    return Err(Error::RevealEnded);
+                }
+
+                // Verificar que el voto revelado coincida con el compromiso
+                let commitment = this.commitments.get((proposal_id, caller)).ok_or(Error::HasNotVoted)?;
+                let revealed = this
+                    .env()
+                    .hash_bytes::<Blake2x256>(&(choice, salt).encode());
+                if revealed != commitment {
+                    return Err(Error::CommitmentMismatch);
+                }
+                proposal.validate_choice(choice)?;
+                let weight = this.ensure_weight(caller)?;
+
+                this.record_vote(proposal_id, &mut proposal, caller, choice, weight)?;
+                this.commitments.remove((proposal_id, caller));
+                Ok(())
+            })
+        }
+
+        /// Retira el voto del caller mientras la propuesta siga abierta, permitiéndole votar de nuevo.
+        /// En modo ponderado se devuelve el valor aportado.
+        #[ink(message)]
+        pub fn revoke_vote(&mut self, proposal_id: u32) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_voting_not_paused()?;
+                let caller = this.env().caller();
+                let mut proposal = this.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+                this.ensure_open(&proposal)?;
+                let choice = this.voters.take((proposal_id, caller)).ok_or(Error::HasNotVoted)?;
+
+                // Descontar el peso aportado y olvidar al votante
+                let weight = this.vote_weights.take((proposal_id, caller)).unwrap_or(0);
+                let contribution = this.contributions.take((proposal_id, caller)).unwrap_or(0);
+                this.rankings.remove((proposal_id, caller));
+                this.vote_reasons.remove((proposal_id, caller));
+                if let Some(approval) = this.approvals.take((proposal_id, caller)) {
+                    for index in approval.iter().skip(1) {
+                        proposal.remove_votes(VoteChoice::Option(*index), weight);
+                        proposal.approval_overlap = proposal.approval_overlap.saturating_sub(weight);
+                    }
+                }
+                if let Some(allocation) = this.allocations.take((proposal_id, caller)) {
+                    for (index, points) in allocation.iter().skip(1) {
+                        proposal.remove_votes(VoteChoice::Option(*index), *points);
+                    }
+                }
+                if let Some(spent) = this.spent_credits.take((proposal_id, caller)) {
+                    let credits = this.credits_of(caller).saturating_add(spent);
+                    this.credits.insert(caller, &credits);
+                }
+                proposal.remove_votes(choice, weight);
+                this.proposals.insert(proposal_id, &proposal);
+                this.drop_voter(proposal_id, caller);
+                this.release_delegators(proposal_id, caller);
+                this.total_votes_cast = this.total_votes_cast.saturating_sub(1);
+                this.forget_vote(proposal_id, caller);
+                this.bump_nonce(caller);
+
+                // Reembolso después de actualizar el estado
+                this.locked_funds = this.locked_funds.saturating_sub(contribution);
+                if contribution > 0 {
+                    this.env()
+                        .transfer(caller, contribution)
+                        .map_err(|_| Error::TransferFailed)?;
+                }
+                this.env().emit_event(VoteRevoked { proposal_id, voter: caller });
+
+                Ok(())
+            })
+        }
+
+        /// Mueve el voto del caller a otra opción, conservando su peso.
+        /// Un voto por ranking pasa a tener como única preferencia la nueva opción.
+        #[ink(message)]
+        pub fn change_vote(&mut self, proposal_id: u32, new_choice: VoteChoice) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_voting_not_paused()?;
+                let caller = this.env().caller();
+                let mut proposal = this.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+                this.ensure_open(&proposal)?;
+                let old_choice = this.voters.get((proposal_id, caller)).ok_or(Error::HasNotVoted)?;
+                if this.approvals.contains((proposal_id, caller)) || this.allocations.contains((proposal_id, caller)) {
+                    return Err(Error::WrongVotingMode);
+                }
+                if old_choice == new_choice {
+                    return Err(Error::SameChoice);
+                }
+                proposal.validate_choice(new_choice)?;
+
+                // Mover el peso de una opción a la otra
+                let weight = this.vote_weights.get((proposal_id, caller)).unwrap_or(0);
+                proposal.remove_votes(old_choice, weight);
+                proposal.add_votes(new_choice, weight);
+                this.proposals.insert(proposal_id, &proposal);
+                this.voters.insert((proposal_id, caller), &new_choice);
+                this.rankings.remove((proposal_id, caller));
+                this.bump_nonce(caller);
+                this.env().emit_event(VoteChanged {
+                    proposal_id,
+                    voter: caller,
+                    old_choice,
+                    new_choice,
+                });
+
+                Ok(())
+            })
+        }
+
+        /// Cierra la votación de una propuesta antes de su plazo. Accesible por el owner y los moderadores.
+        #[ink(message)]
+        pub fn close_proposal(&mut self, proposal_id: u32) -> Result<(), Error> {
+            self.ensure_moderator()?;
+            self.close_checked(proposal_id)
+        }
+
+        /// Cierra una propuesta activa, una vez verificados los permisos del caller. En modo secreto
+        /// deja de aceptar compromisos y la fase de revelación empieza en el bloque actual si todavía
+        /// no había comenzado.
+        fn close_checked(&mut self, proposal_id: u32) -> Result<(), Error> {
+            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            if proposal.status != ProposalStatus::Active {
+                return Err(Error::ProposalClosed);
+            }
+
+            let now = self.env().block_number();
+            proposal.reveal_block = proposal.reveal_block.map(|block| block.min(now));
+            self.set_status(&mut proposal, ProposalStatus::Closed);
+            self.proposals.insert(proposal_id, &proposal);
+            self.env().emit_event(ProposalClosed {
+                id: proposal_id,
+                total_votes: proposal.total_votes(),
+            });
+
+            Ok(())
+        }
+
+        /// Patrocina una propuesta en borrador. Al alcanzar `settings.min_sponsors` patrocinios
+        /// distintos la propuesta pasa a estar activa. El plazo de votación se cuenta desde la
+        /// creación, por lo que el tiempo en borrador lo acorta.
+        #[ink(message)]
+        pub fn sponsor(&mut self, proposal_id: u32) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.ensure_not_blacklisted(caller)?;
+            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            if proposal.status != ProposalStatus::Draft {
+                return Err(Error::NotDraft);
+            }
+            if self.sponsors.insert((proposal_id, caller), &true).is_some() {
+                return Err(Error::AlreadySponsored);
+            }
+
+            self.sponsor_list.insert((proposal_id, proposal.sponsor_count), &caller);
+            proposal.sponsor_count = proposal.sponsor_count.saturating_add(1);
+            if proposal.sponsor_count >= self.settings.min_sponsors {
+                self.set_status(&mut proposal, ProposalStatus::Active);
+                self.env().emit_event(ProposalActivated { id: proposal_id });
+            }
+            self.proposals.insert(proposal_id, &proposal);
+            Ok(())
+        }
+
+        /// Cancela una propuesta abierta que todavía no recibió votos. Solo accesible por su creador.
+        #[ink(message)]
+        pub fn cancel_proposal(&mut self, proposal_id: u32) -> Result<(), Error> {
+            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            if self.env().caller() != proposal.creator {
+                return Err(Error::Unauthorized);
+            }
+            self.ensure_not_finished(&proposal)?;
+            if self.voter_count(proposal_id) > 0 {
+                return Err(Error::ProposalHasVotes);
+            }
+
+            self.set_status(&mut proposal, ProposalStatus::Cancelled);
+            self.proposals.insert(proposal_id, &proposal);
+            self.env().emit_event(ProposalCancelled { id: proposal_id });
+
+            Ok(())
+        }
+
+        /// Cancela una propuesta todavía no finalizada, aunque ya tenga votos. Solo accesible por el
+        /// administrador. Los votantes recuperan lo aportado mediante `claim_refund`.
+        #[ink(message)]
+        pub fn force_cancel(&mut self, proposal_id: u32) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.force_cancel_checked(proposal_id)
+        }
+
+        /// Cancela una propuesta no finalizada, una vez verificados los permisos del caller.
+        fn force_cancel_checked(&mut self, proposal_id: u32) -> Result<(), Error> {
+            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            match self.effective_status(&proposal) {
+                ProposalStatus::Finalized => return Err(Error::AlreadyFinalized),
+                ProposalStatus::Cancelled => return Err(Error::ProposalCancelled),
+                _ => {}
+            }
+
+            self.set_status(&mut proposal, ProposalStatus::Cancelled);
+            self.proposals.insert(proposal_id, &proposal);
+            self.env().emit_event(ProposalCancelled { id: proposal_id });
+
+            Ok(())
+        }
+
+        /// Reemplaza la descripción de una propuesta en borrador o abierta que todavía no recibió
+        /// votos, guardando la anterior en su historial de ediciones. Solo accesible por su creador.
+        #[ink(message)]
+        pub fn amend_proposal(&mut self, proposal_id: u32, new_description: String) -> Result<(), Error> {
+            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            if self.env().caller() != proposal.creator {
+                return Err(Error::Unauthorized);
+            }
+            match proposal.status {
+                ProposalStatus::Draft | ProposalStatus::Active => {}
+                ProposalStatus::Closed => return Err(Error::ProposalClosed),
+                ProposalStatus::Cancelled => return Err(Error::ProposalCancelled),
+                ProposalStatus::Finalized => return Err(Error::AlreadyFinalized),
+            }
+            if self.voter_count(proposal_id) > 0 {
+                return Err(Error::ProposalHasVotes);
+            }
+            if new_description.len() > MAX_DESCRIPTION_LEN as usize {
+                return Err(Error::DescriptionTooLong);
+            }
+
+            let edits = self.edit_counts.get(proposal_id).unwrap_or(0);
+            let previous = core::mem::replace(&mut proposal.description, new_description);
+            self.edit_history.insert((proposal_id, edits), &previous);
+            self.edit_counts.insert(proposal_id, &edits.saturating_add(1));
+            self.proposals.insert(proposal_id, &proposal);
+            self.env().emit_event(ProposalAmended {
+                id: proposal_id,
+                edits: edits.saturating_add(1),
+            });
+
+            Ok(())
+        }
+
+        /// Corrige el quórum y el umbral de una propuesta abierta que todavía no recibió votos.
+        /// Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn update_thresholds(&mut self, proposal_id: u32, quorum: u32, threshold: u32) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.update_thresholds_checked(proposal_id, quorum, threshold)
+        }
+
+        /// Corrige el quórum y el umbral de una propuesta sin votos, una vez verificados los
+        /// permisos del caller.
+        fn update_thresholds_checked(&mut self, proposal_id: u32, quorum: u32, threshold: u32) -> Result<(), Error> {
+            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            self.ensure_not_finished(&proposal)?;
+            if self.voter_count(proposal_id) > 0 {
+                return Err(Error::ProposalHasVotes);
+            }
+
+            proposal.quorum = quorum;
+            proposal.threshold = threshold;
+            self.proposals.insert(proposal_id, &proposal);
+            self.env().emit_event(ThresholdsUpdated {
+                id: proposal_id,
+                quorum,
+                threshold,
+            });
+
+            Ok(())
+        }
+
+        /// Extiende el plazo de votación de una propuesta abierta. Solo accesible por el administrador.
+        /// En modo secreto la fase de revelación se desplaza junto con el plazo.
+        #[ink(message)]
+        pub fn extend_deadline(&mut self, proposal_id: u32, additional_blocks: u32) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.extend_checked(proposal_id, additional_blocks)
+        }
+
+        /// Extiende el plazo de una propuesta abierta, una vez verificados los permisos del caller.
+        fn extend_checked(&mut self, proposal_id: u32, additional_blocks: u32) -> Result<(), Error> {
+            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            if self.effective_status(&proposal) != ProposalStatus::Active {
+                return Err(Error::ProposalClosed);
+            }
+
+            proposal.end_block = proposal.end_block.saturating_add(additional_blocks);
+            proposal.reveal_block = proposal
+                .reveal_block
+                .map(|block| block.saturating_add(additional_blocks));
+            self.proposals.insert(proposal_id, &proposal);
+            self.env().emit_event(DeadlineExtended {
+                id: proposal_id,
+                end_block: proposal.end_block,
+            });
+
+            Ok(())
+        }
+
+        /// Reabre una propuesta cerrada que todavía no fue finalizada. El nuevo plazo vence
+        /// `additional_blocks` bloques después del plazo anterior o, si ya venció, del bloque
+        /// actual. Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn reopen_proposal(&mut self, proposal_id: u32, additional_blocks: u32) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.reopen_checked(proposal_id, additional_blocks)
+        }
+
+        /// Reabre una propuesta cerrada, una vez verificados los permisos del caller.
+        fn reopen_checked(&mut self, proposal_id: u32, additional_blocks: u32) -> Result<(), Error> {
+            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            match self.effective_status(&proposal) {
+                ProposalStatus::Finalized => return Err(Error::AlreadyFinalized),
+                ProposalStatus::Cancelled => return Err(Error::ProposalCancelled),
+                ProposalStatus::Closed => {}
+                _ => return Err(Error::ProposalNotClosed),
+            }
+
+            let end_block = proposal
+                .end_block
+                .max(self.env().block_number())
+                .saturating_add(additional_blocks);
+            let shift = end_block - proposal.end_block;
+            proposal.end_block = end_block;
+            proposal.reveal_block = proposal.reveal_block.map(|block| block.saturating_add(shift));
+            if proposal.status == ProposalStatus::Closed {
+                self.set_status(&mut proposal, ProposalStatus::Active);
+            }
+            self.proposals.insert(proposal_id, &proposal);
+            self.env().emit_event(ProposalReopened { id: proposal_id, end_block });
+
+            Ok(())
+        }
+
+        /// Elimina una propuesta y sus registros de votantes. Accesible por el owner y los moderadores.
+        /// El depósito se devuelve al creador, y los aportes de valor y los créditos de voto
+        /// cuadrático gastados, a cada votante.
+        /// El ID eliminado no se reutiliza.
+        #[ink(message)]
+        pub fn delete_proposal(&mut self, proposal_id: u32) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_moderator()?;
+                this.delete_checked(proposal_id)
+            })
+        }
+
+        /// Elimina una propuesta y devuelve sus fondos retenidos, una vez verificados los permisos
+        /// del caller.
+        fn delete_checked(&mut self, proposal_id: u32) -> Result<(), Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+
+            // Limpiar los registros asociados antes de devolver los fondos retenidos
+            let mut refunds = Vec::new();
+            if proposal.deposit > 0 {
+                refunds.push((proposal.creator, proposal.deposit));
+            }
+            let voter_count = self.voter_counts.take(proposal_id).unwrap_or(0);
+            self.total_votes_cast = self.total_votes_cast.saturating_sub(voter_count);
+            for position in 0..voter_count {
+                let Some(voter) = self.proposal_voters.take((proposal_id, position)) else {
+                    continue;
+                };
+                self.voter_positions.remove((proposal_id, voter));
+                self.voters.remove((proposal_id, voter));
+                self.vote_weights.remove((proposal_id, voter));
+                if let Some(contribution) = self.contributions.take((proposal_id, voter)) {
+                    refunds.push((voter, contribution));
+                }
+                if let Some(spent) = self.spent_credits.take((proposal_id, voter)) {
+                    let credits = self.credits_of(voter).saturating_add(spent);
+                    self.credits.insert(voter, &credits);
+                }
+                self.rankings.remove((proposal_id, voter));
+                self.approvals.remove((proposal_id, voter));
+                self.allocations.remove((proposal_id, voter));
+                self.vote_reasons.remove((proposal_id, voter));
+                self.token_balances.remove((proposal_id, voter));
+                self.release_delegators(proposal_id, voter);
+                self.forget_vote(proposal_id, voter);
+            }
+            for position in 0..self.commit_counts.take(proposal_id).unwrap_or(0) {
+                if let Some(committer) = self.committers.take((proposal_id, position)) {
+                    self.commitments.remove((proposal_id, committer));
+                }
+            }
+            for position in 0..proposal.sponsor_count {
+                if let Some(sponsor) = self.sponsor_list.take((proposal_id, position)) {
+                    self.sponsors.remove((proposal_id, sponsor));
+                }
+            }
+            for index in 0..self.edit_counts.take(proposal_id).unwrap_or(0) {
+                self.edit_history.remove((proposal_id, index));
+            }
+            self.proposals.remove(proposal_id);
+            self.live_count = self.live_count.saturating_sub(1);
+            let count = self.status_count(proposal.status).saturating_sub(1);
+            self.status_counts.insert(proposal.status, &count);
+            // Liberar el título para que pueda reutilizarse
+            if self.settings.dedupe_titles {
+                self.titles.remove(&proposal.title);
+            }
+            self.env().emit_event(ProposalDeleted { id: proposal_id });
+
+            for (account, amount) in refunds {
+                self.locked_funds = self.locked_funds.saturating_sub(amount);
+                self.env()
+                    .transfer(account, amount)
+                    .map_err(|_| Error::TransferFailed)?;
+            }
+            Ok(())
+        }
+
+        /// Pausa la creación de propuestas y la emisión o modificación de votos.
+        /// Solo accesible por el administrador. Las consultas siguen disponibles.
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.set_paused(true);
+            Ok(())
+        }
+
+        /// Reanuda los votos y la creación de propuestas. Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.set_paused(false);
+            Ok(())
+        }
+
+        /// Activa o desactiva ambas pausas y emite el evento correspondiente.
+        fn set_paused(&mut self, paused: bool) {
+            self.voting_paused = paused;
+            self.creation_paused = paused;
+            let by = self.env().caller();
+            if paused {
+                self.env().emit_event(Paused { by });
+            } else {
+                self.env().emit_event(Unpaused { by });
+            }
+        }
+
+        /// Indica si los votos o la creación de propuestas están pausados.
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.voting_paused || self.creation_paused
+        }
+
+        /// Pausa o reanuda solo la emisión y modificación de votos (incluidas delegaciones y
+        /// registros). Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn set_voting_paused(&mut self, paused: bool) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.voting_paused = paused;
+            Ok(())
+        }
+
+        /// Pausa o reanuda solo la creación de propuestas, dejando que las votaciones en curso
+        /// terminen. Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn set_creation_paused(&mut self, paused: bool) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.creation_paused = paused;
+            Ok(())
+        }
+
+        /// Fondos libres del contrato: su saldo menos los depósitos y aportes reembolsables.
+        /// Incluye el excedente transferido al crear propuestas y los fondos de las eliminadas.
+        #[ink(message)]
+        pub fn treasury_balance(&self) -> Balance {
+            self.env().balance().saturating_sub(self.locked_funds)
+        }
+
+        /// Transfiere fondos libres de la tesorería a `to`. Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn withdraw_treasury(&mut self, amount: Balance, to: AccountId) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_owner()?;
+                this.withdraw_checked(amount, to)
+            })
+        }
+
+        /// Transfiere fondos libres de la tesorería, una vez verificados los permisos del caller.
+        fn withdraw_checked(&mut self, amount: Balance, to: AccountId) -> Result<(), Error> {
+            if amount > self.treasury_balance() {
+                return Err(Error::InsufficientTreasury);
+            }
+            self.env()
+                .transfer(to, amount)
+                .map_err(|_| Error::TransferFailed)?;
+            self.env().emit_event(TreasuryWithdrawn { to, amount });
+            Ok(())
+        }
+
+        /// Registra los votos a favor actuales de todas las propuestas existentes, asociados al
+        /// bloque actual. Solo accesible por el administrador. Una segunda instantánea en el mismo
+        /// bloque reemplaza a la anterior. Recorre todas las propuestas, por lo que su costo crece
+        /// con `proposal_count` en cada instantánea.
+        #[ink(message)]
+        pub fn snapshot(&mut self) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.snapshot_checked()
+        }
+
+        /// Registra una instantánea de los votos a favor, una vez verificados los permisos del
+        /// caller.
+        fn snapshot_checked(&mut self) -> Result<(), Error> {
+            let block = self.env().block_number();
+            for id in 0..self.proposal_count {
+                if let Some(proposal) = self.proposals.get(id) {
+                    self.snapshots.insert((block, id), &proposal.yes_votes);
+                }
+            }
+            let last = self.snapshot_count.checked_sub(1).and_then(|index| self.snapshot_blocks.get(index));
+            if last != Some(block) {
+                self.snapshot_blocks.insert(self.snapshot_count, &block);
+                self.snapshot_count = self.snapshot_count.saturating_add(1);
+            }
+            Ok(())
+        }
+
+        /// Votos a favor que tenía una propuesta en la instantánea tomada en `snapshot_block`.
+        #[ink(message)]
+        pub fn get_snapshot(&self, snapshot_block: u32, proposal_id: u32) -> Result<u32, Error> {
+            self.snapshots
+                .get((snapshot_block, proposal_id))
+                .ok_or(Error::SnapshotNotFound)
+        }
+
+        /// Bloques en los que se tomaron instantáneas, en orden, a partir de la posición `start`
+        /// y en páginas de hasta `MAX_PAGE_SIZE`.
+        #[ink(message)]
+        pub fn get_snapshot_blocks(&self, start: u32, limit: u32) -> Vec<u32> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.snapshot_count);
+            (start..end)
+                .filter_map(|index| self.snapshot_blocks.get(index))
+                .collect()
+        }
+
+        /// Cantidad de bloques en los que se tomaron instantáneas.
+        #[ink(message)]
+        pub fn get_snapshot_count(&self) -> u32 {
+            self.snapshot_count
+        }
+
+        /// Reemplaza el código del contrato conservando su storage. Solo accesible por el administrador.
+        ///
+        /// El nuevo código debe ser compatible con el layout de `VotingSystem`: mantener los campos
+        /// existentes con el mismo orden y tipo, agregar los nuevos solo al final y no cambiar la
+        /// codificación de los tipos guardados (`Proposal`, `Settings`, `VoteChoice`, ...). De lo
+        /// contrario el storage actual no podrá decodificarse tras la actualización.
+        #[ink(message)]
+        pub fn set_code_hash(&mut self, code_hash: Hash) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.set_code_checked(code_hash)
+        }
+
+        /// Reemplaza el código del contrato, una vez verificados los permisos del caller.
+        fn set_code_checked(&mut self, code_hash: Hash) -> Result<(), Error> {
+            self.env()
+                .set_code_hash(&code_hash)
+                .map_err(|_| Error::UpgradeFailed)?;
+            self.env().emit_event(CodeUpgraded { code_hash });
+
+            Ok(())
+        }
+
+        /// Bloquea a una cuenta para que no pueda votar ni crear propuestas.
+        /// Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn blacklist(&mut self, account: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.blacklist_checked(account)
+        }
+
+        /// Bloquea a una cuenta, una vez verificados los permisos del caller.
+        fn blacklist_checked(&mut self, account: AccountId) -> Result<(), Error> {
+            self.blacklisted.insert(account, &true);
+            self.env().emit_event(Blacklisted { account });
+            Ok(())
+        }
+
+        /// Desbloquea a una cuenta. Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn unblacklist(&mut self, account: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.unblacklist_checked(account)
+        }
+
+        /// Desbloquea a una cuenta, una vez verificados los permisos del caller.
+        fn unblacklist_checked(&mut self, account: AccountId) -> Result<(), Error> {
+            self.blacklisted.remove(account);
+            self.env().emit_event(Unblacklisted { account });
+            Ok(())
+        }
+
+        /// Indica si una cuenta está bloqueada.
+        #[ink(message)]
+        pub fn is_blacklisted(&self, account: AccountId) -> bool {
+            self.blacklisted.contains(account)
+        }
+
+        /// Designa a una cuenta como moderador. Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn add_moderator(&mut self, account: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.add_moderator_checked(account)
+        }
+
+        /// Designa a un moderador, una vez verificados los permisos del caller.
+        fn add_moderator_checked(&mut self, account: AccountId) -> Result<(), Error> {
+            if self.moderators.insert(account, &true).is_none() {
+                self.moderator_list.insert(self.moderator_count, &account);
+                self.moderator_positions.insert(account, &self.moderator_count);
+                self.moderator_count = self.moderator_count.saturating_add(1);
+            }
+            self.env().emit_event(ModeratorAdded { account });
+            Ok(())
+        }
+
+        /// Quita a una cuenta del rol de moderador. Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn remove_moderator(&mut self, account: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.remove_moderator_checked(account)
+        }
+
+        /// Quita a un moderador, una vez verificados los permisos del caller.
+        fn remove_moderator_checked(&mut self, account: AccountId) -> Result<(), Error> {
+            self.moderators.remove(account);
+            if let Some(position) = self.moderator_positions.take(account) {
+                let last = self.moderator_count.saturating_sub(1);
+                if let Some(moved) = self.moderator_list.take(last) {
+                    if position != last {
+                        self.moderator_list.insert(position, &moved);
+                        self.moderator_positions.insert(moved, &position);
+                    }
+                }
+                self.moderator_count = last;
+            }
+            self.env().emit_event(ModeratorRemoved { account });
+            Ok(())
+        }
+
+        /// Indica si una cuenta es moderador.
+        #[ink(message)]
+        pub fn is_moderator(&self, account: AccountId) -> bool {
+            self.moderators.contains(account)
+        }
+
+        /// Retorna los moderadores actuales a partir de la posición `start`, en páginas de hasta
+        /// `MAX_PAGE_SIZE`. Siguen el orden en que fueron agregados, salvo que al quitarse uno el
+        /// último pasa a ocupar su lugar.
+        #[ink(message)]
+        pub fn get_moderators(&self, start: u32, limit: u32) -> Vec<AccountId> {
+            let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(self.moderator_count);
+            (start..end).filter_map(|position| self.moderator_list.get(position)).collect()
+        }
+
+        /// Cantidad de moderadores actuales.
+        #[ink(message)]
+        pub fn get_moderator_count(&self) -> u32 {
+            self.moderator_count
+        }
+
+        /// Retorna el owner actual; tras `renounce_ownership` es la cuenta nula.
+        #[ink(message)]
+        pub fn get_owner(&self) -> AccountId {
+            self.owner
+        }
+
+        /// Consulta toda la configuración vigente del contrato en una sola llamada.
+        #[ink(message)]
+        pub fn get_config(&self) -> Config {
+            let settings = &self.settings;
+            Config {
+                owner: self.owner,
+                pending_owner: self.pending_owner,
+                owners: settings.owners.clone(),
+                required_approvals: settings.required_approvals,
+                max_proposals: settings.max_proposals,
+                proposal_deposit: settings.proposal_deposit,
+                max_title_len: settings.max_title_len,
+                dedupe_titles: settings.dedupe_titles,
+                value_weighted: settings.value_weighted,
+                allowlist_enabled: settings.allowlist_enabled,
+                governance_token: settings.governance_token,
+                min_token_balance: settings.min_token_balance,
+                min_sponsors: settings.min_sponsors,
+                vote_cooldown: settings.vote_cooldown,
+                owner_can_vote: settings.owner_can_vote,
+                creator_can_vote: settings.creator_can_vote,
+                decay_mode: settings.decay_mode,
+                tie_break_random: settings.tie_break_random,
+                reveal_period: settings.reveal_period,
+                registration_open_block: self.registration_open_block,
+                registration_close_block: self.registration_close_block,
+                paused: self.is_paused(),
+                voting_paused: self.voting_paused,
+                creation_paused: self.creation_paused,
+            }
+        }
+
+        /// Owners que gobiernan las acciones multifirma, fijados al desplegar el contrato. Son
+        /// independientes de `get_owner`, que conserva el resto de los permisos de administración.
+        #[ink(message)]
+        pub fn get_owners(&self) -> Vec<AccountId> {
+            self.settings.owners.clone()
+        }
+
+        /// Aprobaciones de owners distintos necesarias para ejecutar una acción de gobierno.
+        #[ink(message)]
+        pub fn required_approvals(&self) -> u32 {
+            self.settings.required_approvals
+        }
+
+        /// Propone una acción de gobierno. Solo accesible por los owners multifirma; la propuesta
+        /// cuenta como la aprobación de quien la crea. Retorna el ID de la acción.
+        #[ink(message)]
+        pub fn propose_action(&mut self, action: GovernanceAction) -> Result<u32, Error> {
+            self.ensure_governor()?;
+            let caller = self.env().caller();
+            let id = self.action_count;
+            self.action_count = id.checked_add(1).ok_or(Error::ProposalCounterOverflow)?;
+            self.pending_actions.insert(
+                id,
+                &PendingAction {
+                    action,
+                    proposer: caller,
+                    approvals: 1,
+                    executed: false,
+                },
+            );
+            self.action_approvals.insert((id, caller), &true);
+            self.env().emit_event(ActionProposed { id, proposer: caller });
+            Ok(id)
+        }
+
+        /// Aprueba una acción de gobierno pendiente. Cada owner multifirma aprueba una sola vez.
+        #[ink(message)]
+        pub fn approve_action(&mut self, action_id: u32) -> Result<(), Error> {
+            self.ensure_governor()?;
+            let caller = self.env().caller();
+            let mut pending = self.pending_actions.get(action_id).ok_or(Error::ActionDoesNotExist)?;
+            if pending.executed {
+                return Err(Error::AlreadyExecuted);
+            }
+            if self.action_approvals.contains((action_id, caller)) {
+                return Err(Error::AlreadyApproved);
+            }
+
+            self.action_approvals.insert((action_id, caller), &true);
+            pending.approvals = pending.approvals.saturating_add(1);
+            self.pending_actions.insert(action_id, &pending);
+            self.env().emit_event(ActionApproved {
+                id: action_id,
+                approver: caller,
+                approvals: pending.approvals,
+            });
+            Ok(())
+        }
+
+        /// Ejecuta una acción de gobierno que reunió `required_approvals` aprobaciones. Solo
+        /// accesible por los owners multifirma; al crear una propuesta, quien ejecuta figura como
+        /// creador y debe transferir el depósito configurado.
+        #[ink(message, payable)]
+        pub fn execute_action(&mut self, action_id: u32) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                this.ensure_governor()?;
+                let mut pending = this.pending_actions.get(action_id).ok_or(Error::ActionDoesNotExist)?;
+                if pending.executed {
+                    return Err(Error::AlreadyExecuted);
+                }
+                if pending.approvals < this.settings.required_approvals {
+                    return Err(Error::NotEnoughApprovals);
+                }
+
+                match pending.action.clone() {
+                    GovernanceAction::CreateProposal { title, description, params } => {
+                        this.create_checked(title, description, *params)?;
+                    }
+                    GovernanceAction::CloseProposal(proposal_id) => this.close_checked(proposal_id)?,
+                    GovernanceAction::Pause => this.set_paused(true),
+                    GovernanceAction::Unpause => this.set_paused(false),
+                    GovernanceAction::SetVotingPaused(paused) => this.voting_paused = paused,
+                    GovernanceAction::SetCreationPaused(paused) => this.creation_paused = paused,
+                    GovernanceAction::DeleteProposal(proposal_id) => this.delete_checked(proposal_id)?,
+                    GovernanceAction::WithdrawTreasury { amount, to } => this.withdraw_checked(amount, to)?,
+                    GovernanceAction::SetCodeHash(code_hash) => this.set_code_checked(code_hash)?,
+                    GovernanceAction::CreateProposals { titles, params } => {
+                        this.create_batch_checked(titles, *params)?;
+                    }
+                    GovernanceAction::ForceCancel(proposal_id) => this.force_cancel_checked(proposal_id)?,
+                    GovernanceAction::UpdateThresholds { proposal_id, quorum, threshold } => {
+                        this.update_thresholds_checked(proposal_id, quorum, threshold)?
+                    }
+                    GovernanceAction::ExtendDeadline { proposal_id, additional_blocks } => {
+                        this.extend_checked(proposal_id, additional_blocks)?
+                    }
+                    GovernanceAction::ReopenProposal { proposal_id, additional_blocks } => {
+                        this.reopen_checked(proposal_id, additional_blocks)?
+                    }
+                    GovernanceAction::Veto(proposal_id) => this.veto_checked(proposal_id)?,
+                    GovernanceAction::Snapshot => this.snapshot_checked()?,
+                    GovernanceAction::Blacklist(account) => this.blacklist_checked(account)?,
+                    GovernanceAction::Unblacklist(account) => this.unblacklist_checked(account)?,
+                    GovernanceAction::AddModerator(account) => this.add_moderator_checked(account)?,
+                    GovernanceAction::RemoveModerator(account) => this.remove_moderator_checked(account)?,
+                    GovernanceAction::AddVoter(account) => this.add_voter_checked(account)?,
+                    GovernanceAction::RemoveVoter(account) => this.remove_voter_checked(account)?,
+                    GovernanceAction::OpenRegistration { from, to } => this.open_registration_checked(from, to)?,
+                    GovernanceAction::SetEligibilityRoot { proposal_id, root } => {
+                        this.set_root_checked(proposal_id, root)?
+                    }
+                    GovernanceAction::SetAllowlistEnabled(enabled) => this.set_allowlist_checked(enabled)?,
+                    GovernanceAction::GrantCredits { account, amount } => this.grant_credits_checked(account, amount)?,
+                    GovernanceAction::SetReputation { account, value } => this.set_reputation_checked(account, value)?,
+                    GovernanceAction::SetWeight { account, weight } => this.set_weight_checked(account, weight)?,
+                    GovernanceAction::TransferOwnership(new_owner) => this.transfer_checked(new_owner)?,
+                    GovernanceAction::RenounceOwnership => this.change_owner(Self::zero_account()),
+                }
+                pending.executed = true;
+                this.pending_actions.insert(action_id, &pending);
+                this.env().emit_event(ActionExecuted { id: action_id });
+                Ok(())
+            })
+        }
+
+        /// Consulta una acción de gobierno y sus aprobaciones.
+        #[ink(message)]
+        pub fn get_action(&self, action_id: u32) -> Result<PendingAction, Error> {
+            self.pending_actions.get(action_id).ok_or(Error::ActionDoesNotExist)
+        }
+
+        /// Habilita a una cuenta para votar. Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn add_voter(&mut self, account: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.add_voter_checked(account)
+        }
+
+        /// Habilita a una cuenta para votar, una vez verificados los permisos del caller.
+        fn add_voter_checked(&mut self, account: AccountId) -> Result<(), Error> {
+            if self.allowlist.insert(account, &true).is_none() {
+                self.registered_voters = self.registered_voters.saturating_add(1);
+            }
+            Ok(())
+        }
+
+        /// Quita a una cuenta de la lista de votantes habilitados. Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn remove_voter(&mut self, account: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.remove_voter_checked(account)
+        }
+
+        /// Quita a una cuenta de los votantes habilitados, una vez verificados los permisos del
+        /// caller.
+        fn remove_voter_checked(&mut self, account: AccountId) -> Result<(), Error> {
+            if self.allowlist.take(account).is_some() {
+                self.registered_voters = self.registered_voters.saturating_sub(1);
+            }
+            Ok(())
+        }
+
+        /// Abre el registro de votantes entre los bloques `from` (inclusive) y `to` (exclusive).
+        /// Solo accesible por el administrador. Si `from >= to` el registro queda cerrado.
+        #[ink(message)]
+        pub fn open_registration(&mut self, from: u32, to: u32) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.open_registration_checked(from, to)
+        }
+
+        /// Fija el período de registro de votantes, una vez verificados los permisos del caller.
+        fn open_registration_checked(&mut self, from: u32, to: u32) -> Result<(), Error> {
+            self.registration_open_block = from;
+            self.registration_close_block = to;
+            Ok(())
+        }
+
+        /// Registra al caller como votante habilitado durante el período de registro.
+        #[ink(message)]
+        pub fn register(&mut self) -> Result<(), Error> {
+            self.ensure_voting_not_paused()?;
+            let block = self.env().block_number();
+            if block < self.registration_open_block || block >= self.registration_close_block {
+                return Err(Error::RegistrationClosed);
+            }
+            let caller = self.env().caller();
+            if self.allowlist.insert(caller, &true).is_some() {
+                return Err(Error::AlreadyRegistered);
+            }
+            self.registered_voters = self.registered_voters.saturating_add(1);
+            self.bump_nonce(caller);
+            Ok(())
+        }
+
+        /// Indica si una cuenta está registrada como votante, por el owner o por su cuenta.
+        #[ink(message)]
+        pub fn is_registered(&self, account: AccountId) -> bool {
+            self.allowlist.contains(account)
+        }
+
+        /// Fija o quita la raíz de Merkle de las cuentas habilitadas para votar en una propuesta.
+        /// Con raíz, se vota con `vote_with_proof` y la lista de votantes no se consulta.
+        /// Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn set_eligibility_root(&mut self, proposal_id: u32, root: Option<[u8; 32]>) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.set_root_checked(proposal_id, root)
+        }
+
+        /// Fija o quita la raíz de elegibilidad de una propuesta, una vez verificados los permisos
+        /// del caller.
+        fn set_root_checked(&mut self, proposal_id: u32, root: Option<[u8; 32]>) -> Result<(), Error> {
+            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            proposal.eligibility_root = root;
+            self.proposals.insert(proposal_id, &proposal);
+            Ok(())
+        }
+
+        /// Activa o desactiva la restricción de votantes. Solo accesible por el administrador.
+        /// Con la restricción desactivada cualquier cuenta puede votar.
+        #[ink(message)]
+        pub fn set_allowlist_enabled(&mut self, enabled: bool) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.set_allowlist_checked(enabled)
+        }
+
+        /// Activa o desactiva la restricción de votantes, una vez verificados los permisos del
+        /// caller.
+        fn set_allowlist_checked(&mut self, enabled: bool) -> Result<(), Error> {
+            self.settings.allowlist_enabled = enabled;
+            Ok(())
+        }
+
+        /// Otorga créditos de voto cuadrático a una cuenta. Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn grant_credits(&mut self, account: AccountId, amount: u64) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.grant_credits_checked(account, amount)
+        }
+
+        /// Otorga créditos de voto cuadrático, una vez verificados los permisos del caller.
+        fn grant_credits_checked(&mut self, account: AccountId, amount: u64) -> Result<(), Error> {
+            let credits = self.credits_of(account).saturating_add(amount);
+            self.credits.insert(account, &credits);
+            Ok(())
+        }
+
+        /// Créditos de voto cuadrático disponibles de una cuenta.
+        #[ink(message)]
+        pub fn credits_of(&self, account: AccountId) -> u64 {
+            self.credits.get(account).unwrap_or(0)
+        }
+
+        /// Asigna la reputación de una cuenta, reemplazando la anterior. Solo accesible por el
+        /// administrador.
+        #[ink(message)]
+        pub fn set_reputation(&mut self, account: AccountId, value: u32) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.set_reputation_checked(account, value)
+        }
+
+        /// Asigna la reputación de una cuenta, una vez verificados los permisos del caller.
+        fn set_reputation_checked(&mut self, account: AccountId, value: u32) -> Result<(), Error> {
+            self.reputation.insert(account, &value);
+            Ok(())
+        }
+
+        /// Asigna el peso con el que vota una cuenta, reemplazando el anterior. Solo accesible por
+        /// el administrador. Con peso 0 la cuenta no puede votar. No aplica en modo ponderado.
+        #[ink(message)]
+        pub fn set_weight(&mut self, account: AccountId, weight: u32) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.set_weight_checked(account, weight)
+        }
+
+        /// Asigna el peso de voto de una cuenta, una vez verificados los permisos del caller.
+        fn set_weight_checked(&mut self, account: AccountId, weight: u32) -> Result<(), Error> {
+            self.account_weights.insert(account, &weight);
+            Ok(())
+        }
+
+        /// Peso de voto asignado a una cuenta; 1 si el owner no le asignó uno.
+        #[ink(message)]
+        pub fn weight_of(&self, account: AccountId) -> u32 {
+            self.account_weights.get(account).unwrap_or(1)
+        }
+
+        /// Reputación de una cuenta; 0 si el owner no le asignó una.
+        #[ink(message)]
+        pub fn reputation_of(&self, account: AccountId) -> u32 {
+            self.reputation.get(account).unwrap_or(0)
+        }
+
+        /// Indica si una cuenta puede votar según la lista de votantes habilitados.
+        #[ink(message)]
+        pub fn is_eligible(&self, account: AccountId) -> bool {
+            !self.settings.allowlist_enabled || self.allowlist.contains(account)
+        }
+
+        /// Propone una nueva cuenta como owner. Solo accesible por el administrador.
+        /// La transferencia no tiene efecto hasta que la cuenta propuesta la acepte.
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.transfer_checked(new_owner)
+        }
+
+        /// Propone un nuevo owner, una vez verificados los permisos del caller.
+        fn transfer_checked(&mut self, new_owner: AccountId) -> Result<(), Error> {
+            if self.is_zero(new_owner) {
+                return Err(Error::ZeroAccount);
+            }
+            self.pending_owner = Some(new_owner);
+            Ok(())
+        }
+
+        /// Acepta la propiedad del contrato. Solo accesible por la cuenta propuesta.
+        #[ink(message)]
+        pub fn accept_ownership(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if self.pending_owner != Some(caller) {
+                return Err(Error::NotPendingOwner);
+            }
+
+            self.change_owner(caller);
+
+            Ok(())
+        }
+
+        /// Renuncia a la propiedad del contrato. Solo accesible por el administrador.
+        /// La acción es irreversible: el owner pasa a ser la cuenta nula, por lo que
+        /// ninguna acción de administrador (incluida `transfer_ownership`) vuelve a ser posible.
+        #[ink(message)]
+        pub fn renounce_ownership(&mut self) -> Result<(), Error> {
+            self.ensure_owner()?;
+
+            self.change_owner(Self::zero_account());
+
+            Ok(())
+        }
+
+        /// Owners que tuvo el contrato, en orden y con la marca de tiempo en que asumió cada uno.
+        /// Incluye al owner inicial y, tras una renuncia, la cuenta nula. Retorna las entradas a
+        /// partir de la posición `start`, en páginas de hasta `MAX_PAGE_SIZE`.
+        #[ink(message)]
+        pub fn owner_history(&self, start: u32, limit: u32) -> Vec<(AccountId, Timestamp)> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.owner_history_len);
+            (start..end)
+                .filter_map(|index| self.owner_history.get(index))
+                .collect()
+        }
+
+        /// Cantidad de entradas del historial de owners.
+        #[ink(message)]
+        pub fn owner_history_count(&self) -> u32 {
+            self.owner_history_len
+        }
+
+        /// Indica si el contrato ya no tiene owner tras `renounce_ownership`.
+        #[ink(message)]
+        pub fn is_ownerless(&self) -> bool {
+            self.is_zero(self.owner)
+        }
+
+        /// Indica si una cuenta es la cuenta nula, que no puede ser owner.
+        #[ink(message)]
+        pub fn is_zero(&self, account: AccountId) -> bool {
+            account == Self::zero_account()
+        }
+
+        /// Consulta pública de los datos de una propuesta.
+        #[ink(message)]
+        pub fn get_proposal(&self, proposal_id: u32) -> Result<ProposalView, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(ProposalView {
+                id: proposal_id,
+                status: self.effective_status(&proposal),
+                title: proposal.title,
+                description: proposal.description,
+                yes_votes: proposal.yes_votes,
+                no_votes: proposal.no_votes,
+                abstain_votes: proposal.abstain_votes,
+                downvotes: proposal.downvotes,
+                quorum: proposal.quorum,
+                threshold: proposal.threshold,
+                end_block: proposal.end_block,
+                creator: proposal.creator,
+                created_at: proposal.created_at,
+                options: proposal.options,
+                option_votes: proposal.option_votes,
+                sponsor_count: proposal.sponsor_count,
+                point_budget: proposal.point_budget,
+                vote_unit: proposal.vote_unit,
+            })
+        }
+
+        /// Versión anterior de `get_proposal`, que se mantendrá por una versión más.
+        /// Retorna el título, los votos a favor, en contra y abstenciones, y el quórum.
+        #[ink(message)]
+        pub fn get_proposal_basic(&self, proposal_id: u32) -> Result<(String, u32, u32, u32, u32), Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok((
+                proposal.title,
+                proposal.yes_votes,
+                proposal.no_votes,
+                proposal.abstain_votes,
+                proposal.quorum,
+            ))
+        }
+
+        /// Puntaje neto de una propuesta: votos a favor menos votos negativos.
+        #[ink(message)]
+        pub fn net_score(&self, proposal_id: u32) -> Result<i64, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(i64::from(proposal.yes_votes) - i64::from(proposal.downvotes))
+        }
+
+        /// Porcentaje de los votantes registrados que votó en una propuesta. Cuenta cuentas
+        /// votantes, no pesos, por lo que los votos delegados no suman participación.
+        #[ink(message)]
+        pub fn turnout(&self, proposal_id: u32) -> Result<u32, Error> {
+            if !self.proposals.contains(proposal_id) {
+                return Err(Error::ProposalDoesNotExist);
+            }
+            if self.registered_voters == 0 {
+                return Err(Error::NoRegisteredVoters);
+            }
+            let voters = u64::from(self.voter_count(proposal_id));
+            let percentage = voters.saturating_mul(100) / u64::from(self.registered_voters);
+            Ok(u32::try_from(percentage).unwrap_or(u32::MAX))
+        }
+
+        /// Indica si la participación alcanza el mínimo de la propuesta.
+        /// Un mínimo de 0 se considera siempre alcanzado.
+        #[ink(message)]
+        pub fn turnout_met(&self, proposal_id: u32) -> Result<bool, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            if proposal.min_turnout == 0 {
+                return Ok(true);
+            }
+            Ok(self.turnout(proposal_id)? >= proposal.min_turnout)
+        }
+
+        /// Indica si los votos emitidos, incluidas las abstenciones, alcanzan el quórum.
+        /// Un quórum de 0 se considera siempre alcanzado.
+        #[ink(message)]
+        pub fn is_quorum_reached(&self, proposal_id: u32) -> Result<bool, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(proposal.quorum_met())
+        }
+
+        /// Retorna solo los votos a favor de una propuesta.
+        /// Se mantiene por compatibilidad con integraciones que leían el antiguo contador único.
+        #[ink(message)]
+        pub fn get_votes(&self, proposal_id: u32) -> Result<u32, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(proposal.yes_votes)
+        }
+
+        /// Lista propuestas a partir del ID `start`, en páginas de hasta `MAX_PAGE_SIZE`.
+        /// Retorna ID, descripción y votos a favor, omitiendo las propuestas eliminadas.
+        #[ink(message)]
+        pub fn list_proposals(&self, start: u32, limit: u32) -> Vec<(u32, String, u32)> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.proposal_count);
+            (start..end)
+                .filter_map(|id| {
+                    self.proposals
+                        .get(id)
+                        .map(|proposal| (id, proposal.title, proposal.yes_votes))
+                })
+                .collect()
+        }
+
+        /// Ranking de hasta `limit` propuestas (máximo `MAX_PAGE_SIZE`) con más votos a favor,
+        /// en orden descendente y desempatando por el ID menor. Retorna pares (ID, votos a favor).
+        /// Recorre y ordena todas las propuestas, con costo O(n log n) en `proposal_count`.
+        #[ink(message)]
+        pub fn top_proposals(&self, limit: u32) -> Vec<(u32, u32)> {
+            let mut ranking: Vec<(u32, u32)> = (0..self.proposal_count)
+                .filter_map(|id| self.proposals.get(id).map(|proposal| (id, proposal.yes_votes)))
+                .collect();
+            ranking.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+            ranking.truncate(limit.min(MAX_PAGE_SIZE) as usize);
+            ranking
+        }
+
+        /// Retorna cada opción de una propuesta junto con sus votos, en orden de índice.
+        /// Las propuestas de sí/no no tienen opciones y retornan una lista vacía.
+        #[ink(message)]
+        pub fn get_option_results(&self, proposal_id: u32) -> Result<Vec<(String, u32)>, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(proposal.options.into_iter().zip(proposal.option_votes).collect())
+        }
+
+        /// Retorna la URI de metadatos de una propuesta, vacía si no tiene.
+        #[ink(message)]
+        pub fn get_metadata(&self, proposal_id: u32) -> Result<String, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(proposal.metadata_uri)
+        }
+
+        /// Retorna las descripciones anteriores de una propuesta, de la más vieja a la más reciente,
+        /// a partir de la posición `start` y en páginas de hasta `MAX_EDIT_PAGE_SIZE`.
+        #[ink(message)]
+        pub fn get_edit_history(&self, proposal_id: u32, start: u32, limit: u32) -> Result<Vec<String>, Error> {
+            if !self.proposals.contains(proposal_id) {
+                return Err(Error::ProposalDoesNotExist);
+            }
+            let end = start
+                .saturating_add(limit.min(MAX_EDIT_PAGE_SIZE))
+                .min(self.edit_counts.get(proposal_id).unwrap_or(0));
+            Ok((start..end)
+                .filter_map(|index| self.edit_history.get((proposal_id, index)))
+                .collect())
+        }
+
+        /// Retorna cuántas descripciones anteriores tiene guardadas una propuesta.
+        #[ink(message)]
+        pub fn get_edit_count(&self, proposal_id: u32) -> Result<u32, Error> {
+            if !self.proposals.contains(proposal_id) {
+                return Err(Error::ProposalDoesNotExist);
+            }
+            Ok(self.edit_counts.get(proposal_id).unwrap_or(0))
+        }
+
+        /// Retorna las etiquetas de una propuesta.
+        #[ink(message)]
+        pub fn get_tags(&self, proposal_id: u32) -> Result<Vec<String>, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(proposal.tags)
+        }
+
+        /// Retorna los IDs de las propuestas que tienen una etiqueta.
+        /// Recorre todas las propuestas, por lo que su costo crece con `proposal_count`.
+        #[ink(message)]
+        pub fn proposals_by_tag(&self, tag: String) -> Vec<u32> {
+            self.filter_proposals(|proposal| proposal.tags.contains(&tag))
+        }
+
+        /// Retorna la cuenta que creó una propuesta.
+        #[ink(message)]
+        pub fn get_creator(&self, proposal_id: u32) -> Result<AccountId, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(proposal.creator)
+        }
+
+        /// Retorna los IDs de las propuestas creadas por una cuenta.
+        /// Recorre todas las propuestas, por lo que su costo crece con `proposal_count`.
+        #[ink(message)]
+        pub fn proposals_by_creator(&self, account: AccountId) -> Vec<u32> {
+            self.filter_proposals(|proposal| proposal.creator == account)
+        }
+
+        /// Retorna los IDs de las propuestas creadas estrictamente después de la marca de tiempo `ts`.
+        /// Recorre todas las propuestas, por lo que su costo crece con `proposal_count`.
+        #[ink(message)]
+        pub fn proposals_created_after(&self, ts: Timestamp) -> Vec<u32> {
+            self.filter_proposals(|proposal| proposal.created_at > ts)
+        }
+
+        /// Retorna los IDs de las propuestas cuyo título o descripción contienen `needle`,
+        /// distinguiendo mayúsculas, hasta `MAX_PAGE_SIZE` resultados. Un texto vacío o de más de
+        /// `MAX_SEARCH_LEN` bytes no devuelve resultados. Recorre las propuestas en orden, por lo
+        /// que solo conviene en despliegues chicos; con muchas propuestas es preferible indexar
+        /// los eventos `ProposalCreated` fuera de la cadena.
+        #[ink(message)]
+        pub fn search_proposals(&self, needle: String) -> Vec<u32> {
+            if needle.is_empty() || needle.len() > MAX_SEARCH_LEN as usize {
+                return Vec::new();
+            }
+            (0..self.proposal_count)
+                .filter(|id| {
+                    self.proposals.get(id).is_some_and(|proposal| {
+                        proposal.title.contains(needle.as_str()) || proposal.description.contains(needle.as_str())
+                    })
+                })
+                .take(MAX_PAGE_SIZE as usize)
+                .collect()
+        }
+
+        /// Retorna los IDs de las propuestas que aceptan votos en el bloque actual: activas, con
+        /// la votación ya iniciada y dentro de su plazo. Omite borradores, cerradas y vencidas.
+        /// Recorre las propuestas en orden, por lo que su costo crece con `proposal_count`, y se
+        /// detiene al reunir `MAX_PAGE_SIZE` resultados.
+        #[ink(message)]
+        pub fn open_proposals(&self) -> Vec<u32> {
+            let block = self.env().block_number();
+            (0..self.proposal_count)
+                .filter(|id| {
+                    self.proposals.get(id).is_some_and(|proposal| {
+                        proposal.status == ProposalStatus::Active
+                            && proposal.start_block <= block
+                            && block < proposal.end_block
+                    })
+                })
+                .take(MAX_PAGE_SIZE as usize)
+                .collect()
+        }
+
+        /// Cuenta una propuesta de opción múltiple por el método de Borda. Con N opciones, cada voto
+        /// otorga, multiplicado por su peso, N-1 puntos a su primera preferencia, N-2 a la segunda y
+        /// así sucesivamente; las opciones que no rankeó reciben 0. Retorna todas las opciones con
+        /// su puntaje, ordenadas de mayor a menor (ante un empate, por índice menor). Los votos
+        /// simples con `VoteChoice::Option` cuentan como un ranking de una sola opción.
+        #[ink(message)]
+        pub fn tally_borda(&self, proposal_id: u32) -> Result<Vec<(u32, u64)>, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            if proposal.options.is_empty() {
+                return Err(Error::WrongVotingMode);
+            }
+            let options = proposal.options.len();
+            let mut scores: Vec<(u32, u64)> = (0..options as u32).map(|index| (index, 0)).collect();
+            for (ranking, weight) in self.ranked_ballots(proposal_id) {
+                for (position, index) in ranking.iter().enumerate() {
+                    let points = (options - 1 - position) as u64;
+                    let score = &mut scores[*index as usize].1;
+                    *score = score.saturating_add(points.saturating_mul(u64::from(weight)));
+                }
+            }
+            // El ordenamiento es estable, por lo que los empates conservan el orden por índice
+            scores.sort_by_key(|(_, score)| core::cmp::Reverse(*score));
+            Ok(scores)
+        }
+
+        /// Rankings de los votantes de una propuesta de opción múltiple, con el peso de cada voto.
+        /// Los votos simples con `VoteChoice::Option` se toman como un ranking de una sola opción.
+        fn ranked_ballots(&self, proposal_id: u32) -> Vec<(Ranking, u32)> {
+            (0..self.voter_count(proposal_id))
+                .filter_map(|position| self.proposal_voters.get((proposal_id, position)))
+                .filter_map(|voter| {
+                    let ranking = match self.rankings.get((proposal_id, voter)) {
+                        Some(ranking) => ranking,
+                        None => match self.voters.get((proposal_id, voter))? {
+                            VoteChoice::Option(index) => ink::prelude::vec![index],
+                            _ => return None,
+                        },
+                    };
+                    Some((ranking, self.get_vote_weight(proposal_id, voter)))
+                })
+                .collect()
+        }
+
+        /// Cuenta una propuesta de opción múltiple por segunda vuelta instantánea y retorna el
+        /// índice de la opción ganadora. En cada ronda cada voto cuenta, con su peso, para su
+        /// preferencia más alta todavía en carrera; los votos sin preferencias restantes se
+        /// descartan. Gana la opción con mayoría absoluta de los votos no descartados; si no hay,
+        /// se elimina la de menos votos (ante un empate, la de índice más alto) y se repite.
+        /// Los votos simples con `VoteChoice::Option` cuentan como un ranking de una sola opción.
+        /// Recorre todos los votos en cada ronda, con costo O(votantes × opciones²).
+        #[ink(message)]
+        pub fn tally_irv(&self, proposal_id: u32) -> Result<u32, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            if proposal.options.is_empty() {
+                return Err(Error::WrongVotingMode);
+            }
+            let ballots = self.ranked_ballots(proposal_id);
+
+            let mut active = ink::prelude::vec![true; proposal.options.len()];
+            loop {
+                let mut counts = ink::prelude::vec![0u64; proposal.options.len()];
+                let mut total: u64 = 0;
+                for (ranking, weight) in &ballots {
+                    if let Some(index) = ranking.iter().find(|index| active[**index as usize]) {
+                        counts[*index as usize] += u64::from(*weight);
+                        total += u64::from(*weight);
+                    }
+                }
+                if total == 0 {
+                    return Err(Error::NoVotes);
+                }
+
+                let remaining: Vec<usize> = (0..active.len()).filter(|index| active[*index]).collect();
+                // Con mayoría absoluta el líder es único, así que solo se desempata al eliminar
+                let leader = remaining.iter().copied().fold(remaining[0], |best, index| {
+                    if counts[index] > counts[best] { index } else { best }
+                });
+                if counts[leader].saturating_mul(2) > total || remaining.len() == 1 {
+                    return Ok(leader as u32);
+                }
+                let fewest = remaining.iter().map(|index| counts[*index]).min().unwrap_or(0);
+                let tied: Vec<u32> = remaining
+                    .iter()
+                    .filter(|index| counts[**index] == fewest)
+                    .map(|index| *index as u32)
+                    .collect();
+                let loser = if self.settings.tie_break_random {
+                    self.random_pick(&tied)
+                } else {
+                    tied[tied.len() - 1]
+                };
+                active[loser as usize] = false;
+            }
+        }
+
+        /// Retorna el ID con más votos a favor entre las propuestas indicadas.
+        /// Ante un empate gana el ID más bajo, o uno elegido con `random_pick` si
+        /// `settings.tie_break_random` está activo.
+        #[ink(message)]
+        pub fn winner(&self, proposal_ids: Vec<u32>) -> Result<u32, Error> {
+            let mut best_votes: Option<u32> = None;
+            let mut tied: Vec<u32> = Vec::new();
+            for id in proposal_ids {
+                let proposal = self.proposals.get(id).ok_or(Error::ProposalDoesNotExist)?;
+                if best_votes.is_none_or(|votes| proposal.yes_votes > votes) {
+                    best_votes = Some(proposal.yes_votes);
+                    tied.clear();
+                }
+                if best_votes == Some(proposal.yes_votes) && !tied.contains(&id) {
+                    tied.push(id);
+                }
+            }
+            if tied.is_empty() {
+                return Err(Error::EmptyProposalSet);
+            }
+            tied.sort_unstable();
+            if self.settings.tie_break_random {
+                Ok(self.random_pick(&tied))
+            } else {
+                Ok(tied[0])
+            }
+        }
+
+        /// Elige uno de `candidates` a partir del hash del bloque actual y de los candidatos. Es
+        /// determinístico para un mismo bloque y conjunto, pero no criptográficamente seguro: el
+        /// número de bloque es público y quien produce el bloque puede elegir cuándo consultarlo.
+        fn random_pick(&self, candidates: &[u32]) -> u32 {
+            let seed = self
+                .env()
+                .hash_bytes::<Blake2x256>(&(self.env().block_number(), candidates).encode());
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&seed[..8]);
+            let index = u64::from_le_bytes(bytes) % candidates.len() as u64;
+            candidates[index as usize]
+        }
+
+        /// Indica si los votos a favor alcanzan el umbral de aprobación de la propuesta y si
+        /// `sí * denominador >= (sí + no) * numerador`. Un umbral de 0 se considera siempre alcanzado.
+        #[ink(message)]
+        pub fn has_passed(&self, proposal_id: u32) -> Result<bool, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(proposal.meets_pass_rule())
+        }
+
+        /// Retorna el estado de una propuesta.
+        /// Una propuesta activa cuyo plazo ya venció se informa como cerrada.
+        #[ink(message)]
+        pub fn get_status(&self, proposal_id: u32) -> Result<ProposalStatus, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(self.effective_status(&proposal))
+        }
+
+        /// Congela el resultado de una propuesta cuya votación terminó. Solo puede hacerse una vez;
+        /// a partir de entonces `get_result` informa siempre el mismo veredicto.
+        #[ink(message)]
+        pub fn finalize(&mut self, proposal_id: u32) -> Result<(), Error> {
+            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            match self.effective_status(&proposal) {
+                ProposalStatus::Finalized => return Err(Error::AlreadyFinalized),
+                ProposalStatus::Cancelled => return Err(Error::ProposalCancelled),
+                ProposalStatus::Closed => {}
+                _ => return Err(Error::VotingStillOpen),
+            }
+            self.freeze_result(proposal_id, &mut proposal);
+            self.proposals.insert(proposal_id, &proposal);
+            Ok(())
+        }
+
+        /// Veta una propuesta que alcanzó la aprobación, con la votación ya cerrada y antes de
+        /// finalizarla. Desde entonces `has_passed` informa falso y `execute` la rechaza.
+        /// Solo accesible por el administrador.
+        #[ink(message)]
+        pub fn veto(&mut self, proposal_id: u32) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.veto_checked(proposal_id)
+        }
+
+        /// Veta una propuesta aprobada, una vez verificados los permisos del caller.
+        fn veto_checked(&mut self, proposal_id: u32) -> Result<(), Error> {
+            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            match self.effective_status(&proposal) {
+                ProposalStatus::Finalized => return Err(Error::AlreadyFinalized),
+                ProposalStatus::Cancelled => return Err(Error::ProposalCancelled),
+                ProposalStatus::Closed => {}
+                _ => return Err(Error::VotingStillOpen),
+            }
+            if !Self::outcome(&proposal) {
+                return Err(Error::ProposalNotPassed);
+            }
+
+            proposal.vetoed = true;
+            self.proposals.insert(proposal_id, &proposal);
+            self.env().emit_event(ProposalVetoed { id: proposal_id });
+            Ok(())
+        }
+
+        /// Indica si el owner vetó una propuesta.
+        #[ink(message)]
+        pub fn is_vetoed(&self, proposal_id: u32) -> Result<bool, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(proposal.vetoed)
+        }
+
+        /// Retorna los votos a favor y si la propuesta fue aprobada, tal como quedaron al finalizarla.
+        #[ink(message)]
+        pub fn get_result(&self, proposal_id: u32) -> Result<(u32, bool), Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            if proposal.status != ProposalStatus::Finalized {
+                return Err(Error::NotFinalized);
+            }
+            Ok((proposal.final_votes, proposal.passed))
+        }
+
+        /// Calcula en una sola consulta los votos, el quórum, el umbral, la aprobación y el
+        /// estado de una propuesta. Una vez finalizada informa los votos y la aprobación
+        /// registrados al finalizarla.
+        #[ink(message)]
+        pub fn full_result(&self, proposal_id: u32) -> Result<ProposalResult, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            let finalized = proposal.status == ProposalStatus::Finalized;
+            let total_votes = proposal.total_votes();
+            Ok(ProposalResult {
+                votes: if finalized { proposal.final_votes } else { proposal.yes_votes },
+                total_votes,
+                quorum: proposal.quorum,
+                quorum_met: proposal.quorum_met(),
+                threshold: proposal.threshold,
+                passed: if finalized { proposal.passed } else { Self::outcome(&proposal) },
+                status: self.effective_status(&proposal),
+            })
+        }
+
+        /// Ejecuta la acción de una propuesta cerrada que alcanzó el quórum y el umbral. Si todavía
+        /// no estaba finalizada, congela su resultado en el mismo paso. La llamada externa corre con
+        /// el cerrojo de reentrada tomado, por lo que el destino no puede volver a entrar al contrato.
+        #[ink(message)]
+        pub fn execute(&mut self, proposal_id: u32) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                let mut proposal = this.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+                if proposal.executed {
+                    return Err(Error::AlreadyExecuted);
+                }
+                match this.effective_status(&proposal) {
+                    ProposalStatus::Cancelled => return Err(Error::ProposalCancelled),
+                    ProposalStatus::Closed | ProposalStatus::Finalized => {}
+                    _ => return Err(Error::VotingStillOpen),
+                }
+                let passed = if proposal.status == ProposalStatus::Finalized {
+                    proposal.passed
+                } else {
+                    Self::outcome(&proposal)
+                };
+                if !passed {
+                    return Err(Error::ProposalNotPassed);
+                }
+                if let Some(dependency) = proposal.depends_on {
+                    let met = this
+                        .proposals
+                        .get(dependency)
+                        .is_some_and(|prerequisite| prerequisite.status == ProposalStatus::Finalized && prerequisite.passed);
+                    if !met {
+                        return Err(Error::DependencyNotMet);
+                    }
+                }
+                let action = proposal.action.clone().ok_or(Error::NotExecutable)?;
+
+                // Marcar como ejecutada antes de llamar para impedir una doble ejecución
+                if proposal.status != ProposalStatus::Finalized {
+                    this.freeze_result(proposal_id, &mut proposal);
+                }
+                proposal.executed = true;
+                this.proposals.insert(proposal_id, &proposal);
+                let result = build_call::<Environment>()
+                    .call(action.target)
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(action.selector))
+                            .push_arg(EncodedInput(&action.input)),
+                    )
+                    .returns::<()>()
+                    .try_invoke();
+                match result {
+                    Ok(Ok(())) => Ok(()),
+                    _ => Err(Error::ExecutionFailed),
+                }
+            })
+        }
+
+        /// Devuelve el depósito de una propuesta a quien lo pagó, una vez terminada la votación.
+        /// Un borrador conserva su depósito hasta que se activa y cierra, o se cancela o elimina.
+        #[ink(message)]
+        pub fn refund_deposit(&mut self, proposal_id: u32) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                let mut proposal = this.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+                match this.effective_status(&proposal) {
+                    ProposalStatus::Closed | ProposalStatus::Finalized | ProposalStatus::Cancelled => {}
+                    ProposalStatus::Active => return Err(Error::VotingStillOpen),
+                    _ => return Err(Error::ProposalNotClosed),
+                }
+                if proposal.deposit == 0 {
+                    return Err(Error::NothingToRefund);
+                }
+
+                // Marcar el depósito como devuelto antes de transferir
+                let amount = proposal.deposit;
+                proposal.deposit = 0;
+                this.proposals.insert(proposal_id, &proposal);
+                this.locked_funds = this.locked_funds.saturating_sub(amount);
+                this.env()
+                    .transfer(proposal.creator, amount)
+                    .map_err(|_| Error::TransferFailed)
+            })
+        }
+
+        /// Devuelve al caller el valor que aportó al votar en una propuesta cancelada o vetada.
+        /// Las propuestas eliminadas devuelven sus aportes en el mismo `delete_proposal`.
+        #[ink(message)]
+        pub fn claim_refund(&mut self, proposal_id: u32) -> Result<(), Error> {
+            self.non_reentrant(|this| {
+                let proposal = this.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+                if proposal.status != ProposalStatus::Cancelled && !proposal.vetoed {
+                    return Err(Error::NotCancelled);
+                }
+                let caller = this.env().caller();
+
+                // Borrar el registro antes de transferir para impedir un doble reclamo
+                let amount = this.contributions.take((proposal_id, caller)).unwrap_or(0);
+                if amount == 0 {
+                    return Err(Error::NothingToRefund);
+                }
+                this.locked_funds = this.locked_funds.saturating_sub(amount);
+                this.env()
+                    .transfer(caller, amount)
+                    .map_err(|_| Error::TransferFailed)
+            })
+        }
+
+        /// Retorna la propuesta de la que depende la ejecución de otra, si la tiene.
+        #[ink(message)]
+        pub fn get_dependency(&self, proposal_id: u32) -> Result<Option<u32>, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(proposal.depends_on)
+        }
+
+        /// Retorna el bloque a partir del cual se aceptan votos en una propuesta.
+        #[ink(message)]
+        pub fn get_start_block(&self, proposal_id: u32) -> Result<u32, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(proposal.start_block)
+        }
+
+        /// Retorna el bloque en el que se mide el saldo del token de gobernanza de los votantes.
+        #[ink(message)]
+        pub fn get_snapshot_block(&self, proposal_id: u32) -> Result<u32, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(proposal.snapshot_block)
+        }
+
+        /// Retorna el bloque en el que cierra la votación de una propuesta.
+        #[ink(message)]
+        pub fn get_deadline(&self, proposal_id: u32) -> Result<u32, Error> {
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+            Ok(proposal.end_block)
+        }
+
+        /// Retorna las cuentas que votaron en una propuesta a partir de la posición `start`, en
+        /// páginas de hasta `MAX_PAGE_SIZE`. Siguen el orden de voto, salvo que al retirarse un voto
+        /// el último votante pasa a ocupar su lugar.
+        #[ink(message)]
+        pub fn get_voters(&self, proposal_id: u32, start: u32, limit: u32) -> Result<Vec<AccountId>, Error> {
+            if !self.proposals.contains(proposal_id) {
+                return Err(Error::ProposalDoesNotExist);
+            }
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.voter_count(proposal_id));
+            Ok((start..end)
+                .filter_map(|position| self.proposal_voters.get((proposal_id, position)))
+                .collect())
+        }
+
+        /// Cantidad de cuentas que votaron en una propuesta.
+        #[ink(message)]
+        pub fn get_voter_count(&self, proposal_id: u32) -> Result<u32, Error> {
+            if !self.proposals.contains(proposal_id) {
+                return Err(Error::ProposalDoesNotExist);
+            }
+            Ok(self.voter_count(proposal_id))
+        }
+
+        /// Indica si una cuenta ya votó en una propuesta. Retorna falso para propuestas inexistentes.
+        #[ink(message)]
+        pub fn has_voted(&self, proposal_id: u32, account: AccountId) -> bool {
+            self.voters.contains((proposal_id, account))
+        }
+
+        /// Retorna los IDs de las propuestas en las que votó una cuenta a partir de la posición
+        /// `start`, en páginas de hasta `MAX_PAGE_SIZE`. Los votos retirados y las propuestas
+        /// eliminadas no figuran: la última entrada pasa a ocupar su lugar.
+        #[ink(message)]
+        pub fn voting_history(&self, account: AccountId, start: u32, limit: u32) -> Vec<u32> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.voting_history_count(account));
+            (start..end)
+                .filter_map(|position| self.voting_history.get((account, position)))
+                .collect()
+        }
+
+        /// Cantidad de propuestas en las que votó una cuenta.
+        #[ink(message)]
+        pub fn voting_history_count(&self, account: AccountId) -> u32 {
+            self.history_counts.get(account).unwrap_or(0)
+        }
+
+        /// Versión por lotes de `has_voted`: retorna, en el mismo orden, si `account` votó en cada
+        /// propuesta de `proposal_ids`. Acepta hasta `MAX_LOOKUP_SIZE` propuestas.
+        #[ink(message)]
+        pub fn has_voted_batch(&self, account: AccountId, proposal_ids: Vec<u32>) -> Result<Vec<bool>, Error> {
+            if proposal_ids.len() > MAX_LOOKUP_SIZE as usize {
+                return Err(Error::BatchTooLarge);
+            }
+            Ok(proposal_ids
+                .into_iter()
+                .map(|proposal_id| self.has_voted(proposal_id, account))
+                .collect())
+        }
+
+        /// Retorna la opción con la que votó una cuenta, o `None` si todavía no votó.
+        #[ink(message)]
+        pub fn get_vote(&self, proposal_id: u32, account: AccountId) -> Result<Option<VoteChoice>, Error> {
+            if !self.proposals.contains(proposal_id) {
+                return Err(Error::ProposalDoesNotExist);
+            }
+            Ok(self.voters.get((proposal_id, account)))
+        }
+
+        /// Retorna el peso con el que votó una cuenta en una propuesta (0 si no votó).
+        #[ink(message)]
+        pub fn get_vote_weight(&self, proposal_id: u32, account: AccountId) -> u32 {
+            self.vote_weights.get((proposal_id, account)).unwrap_or(0)
+        }
+
+        /// Retorna el valor transferido por una cuenta al votar en modo ponderado (0 si no aportó).
+        #[ink(message)]
+        pub fn get_contribution(&self, proposal_id: u32, account: AccountId) -> Balance {
+            self.contributions.get((proposal_id, account)).unwrap_or(0)
+        }
+
+        /// Delega el voto del caller en otra cuenta hasta el bloque `until_block` inclusive,
+        /// reemplazando una delegación previa. Mientras la delegación esté vigente el caller no
+        /// puede votar directamente; al vencer deja de aplicar sin necesidad de retirarla. En las
+        /// propuestas en las que el delegado ya votó con su peso, el caller sigue sin poder votar
+        /// aunque la delegación venza o se retire.
+        /// La cadena resultante no puede superar `MAX_DELEGATION_DEPTH` saltos.
+        #[ink(message)]
+        pub fn delegate(&mut self, to: AccountId, until_block: u32) -> Result<(), Error> {
+            self.ensure_voting_not_paused()?;
+            let caller = self.env().caller();
+            if to == caller {
+                return Err(Error::CannotDelegateToSelf);
+            }
+            if until_block < self.env().block_number() {
+                return Err(Error::InvalidExpiry);
+            }
+
+            // Recorrer la cadena vigente del delegado para detectar ciclos y medir su largo
+            let mut current = to;
+            let mut hops: u32 = 1;
+            while let Some(next) = self.active_delegate(current) {
+                if next == caller {
+                    return Err(Error::DelegationCycle);
+                }
+                current = next;
+                hops = hops.saturating_add(1);
+            }
+            // La cadena completa incluye a quienes ya delegaron en el caller
+            if hops > MAX_DELEGATION_DEPTH {
+                return Err(Error::DelegationTooDeep);
+            }
+            self.delegated_weight(None, caller, caller, hops, &mut Vec::new())?;
+
+            self.remove_delegation(caller);
+            self.delegations.insert(caller, &(to, until_block));
+            let position = self.delegator_count(to);
+            self.delegators.insert((to, position), &caller);
+            self.delegator_positions.insert(caller, &position);
+            self.delegator_counts.insert(to, &position.saturating_add(1));
+            self.bump_nonce(caller);
+            self.env().emit_event(DelegationChanged {
+                delegator: caller,
+                delegate: Some(to),
+                until_block,
+            });
+
+            Ok(())
+        }
+
+        /// Retira la delegación vigente del caller, que vuelve a poder votar directamente.
+        #[ink(message)]
+        pub fn undelegate(&mut self) -> Result<(), Error> {
+            self.ensure_voting_not_paused()?;
+            let caller = self.env().caller();
+            if self.active_delegate(caller).is_none() {
+                return Err(Error::NotDelegated);
+            }
+            self.remove_delegation(caller);
+            self.bump_nonce(caller);
+            self.env().emit_event(DelegationChanged {
+                delegator: caller,
+                delegate: None,
+                until_block: 0,
+            });
+            Ok(())
+        }
+
+        /// Peso efectivo con el que votaría una cuenta: su propio voto más el de todas las cuentas
+        /// habilitadas que delegaron en ella, directamente o en cadena. Retorna 0 si la cadena
+        /// supera `MAX_DELEGATION_DEPTH`.
+        #[ink(message)]
+        pub fn resolve_weight(&self, account: AccountId) -> u32 {
+            self.delegated_weight(None, account, account, 0, &mut Vec::new())
+                .map_or(0, |delegated| delegated.saturating_add(self.weight_of(account)))
+        }
+
+        /// Retorna la cuenta en la que delegó su voto una cuenta y el último bloque en que la
+        /// delegación aplica, si hay una vigente.
+        #[ink(message)]
+        pub fn get_delegate(&self, account: AccountId) -> Option<(AccountId, u32)> {
+            self.delegations
+                .get(account)
+                .filter(|(_, until_block)| self.env().block_number() <= *until_block)
+        }
+
+        /// Cantidad de propuestas que todavía pueden crearse. Sin límite configurado retorna `u32::MAX`.
+        /// Las propuestas eliminadas siguen ocupando su lugar, ya que sus IDs no se reutilizan.
+        #[ink(message)]
+        pub fn remaining_slots(&self) -> u32 {
+            match self.settings.max_proposals {
+                0 => u32::MAX,
+                max => max.saturating_sub(self.proposal_count),
+            }
+        }
+
+        /// Retorna el número total de propuestas registradas en el sistema.
         #[ink(message)]
         pub fn total_proposals(&self) -> u32 {
             self.proposal_count
         }
-    }
 
-    /// =========================
-    /// TESTS
-    /// =========================
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-        use ink::env::test;
+        /// Cantidad de propuestas activas, cerradas, finalizadas y canceladas, en ese orden.
+        /// Se lee de contadores que se actualizan en cada transición, con costo constante. Como
+        /// vencer el plazo no es una transacción, una propuesta vencida sigue contando como activa
+        /// hasta que se la cierre o finalice.
+        #[ink(message)]
+        pub fn count_by_status(&self) -> (u32, u32, u32, u32) {
+            (
+                self.status_count(ProposalStatus::Active),
+                self.status_count(ProposalStatus::Closed),
+                self.status_count(ProposalStatus::Finalized),
+                self.status_count(ProposalStatus::Cancelled),
+            )
+        }
+
+        /// Retorna la cantidad de propuestas existentes, sin contar las eliminadas.
+        #[ink(message)]
+        pub fn live_proposal_count(&self) -> u32 {
+            self.live_count
+        }
+
+        /// Retorna los IDs de las propuestas que siguen existiendo, salteando las eliminadas.
+        /// Recorre todas las propuestas, por lo que su costo crece con `proposal_count`.
+        #[ink(message)]
+        pub fn active_proposal_ids(&self) -> Vec<u32> {
+            self.filter_proposals(|_| true)
+        }
+
+        /// Retorna el ID de la última propuesta creada que todavía existe, o `None` si no queda
+        /// ninguna. Si las más recientes fueron eliminadas, recorre hacia atrás hasta dar con una.
+        #[ink(message)]
+        pub fn last_proposal_id(&self) -> Option<u32> {
+            (0..self.proposal_count).rev().find(|id| self.proposals.contains(id))
+        }
+
+        /// Estadísticas globales: propuestas creadas, votos registrados en las propuestas
+        /// existentes y cuentas distintas que votaron alguna vez.
+        #[ink(message)]
+        pub fn stats(&self) -> (u32, u32, u32) {
+            (self.proposal_count, self.total_votes_cast, self.unique_voters)
+        }
+
+        /// Valida que el título no esté vacío, no supere la longitud máxima configurada
+        /// y, si corresponde, no esté en uso.
+        fn validate_title(&self, title: &str) -> Result<(), Error> {
+            if title.is_empty() {
+                return Err(Error::EmptyTitle);
+            }
+            if title.len() > self.settings.max_title_len as usize {
+                return Err(Error::TitleTooLong);
+            }
+            if self.settings.dedupe_titles && self.titles.contains(title) {
+                return Err(Error::DuplicateTitle);
+            }
+            Ok(())
+        }
+
+        /// Valida los parámetros de creación de una propuesta. La URI de metadatos comparte
+        /// el límite de longitud del título.
+        fn validate_params(&self, params: &ProposalParams) -> Result<(), Error> {
+            if params.tags.len() > MAX_TAGS as usize {
+                return Err(Error::TooManyTags);
+            }
+            if params.options.len() > MAX_OPTIONS as usize {
+                return Err(Error::TooManyOptions);
+            }
+            if params.metadata_uri.len() > self.settings.max_title_len as usize {
+                return Err(Error::MetadataTooLong);
+            }
+            let now = self.env().block_number();
+            if params.start_block > now && params.start_block >= now.saturating_add(params.duration_blocks) {
+                return Err(Error::InvalidStartBlock);
+            }
+            if params.pass_numerator > params.pass_denominator {
+                return Err(Error::InvalidThreshold);
+            }
+            if params.depends_on.is_some_and(|id| !self.proposals.contains(id)) {
+                return Err(Error::ProposalDoesNotExist);
+            }
+            Ok(())
+        }
+
+        /// Recorre los IDs asignados y retorna los de las propuestas existentes que cumplen el predicado.
+        fn filter_proposals<F: Fn(&Proposal) -> bool>(&self, predicate: F) -> Vec<u32> {
+            (0..self.proposal_count)
+                .filter(|id| self.proposals.get(id).is_some_and(|proposal| predicate(&proposal)))
+                .collect()
+        }
+
+        /// Verifica que queden lugares para crear `count` propuestas más, y que el contador de IDs
+        /// alcance para asignarles uno nuevo a cada una.
+        fn ensure_capacity(&self, count: u32) -> Result<(), Error> {
+            if self.proposal_count.checked_add(count).is_none() {
+                return Err(Error::ProposalCounterOverflow);
+            }
+            if self.remaining_slots() < count {
+                return Err(Error::ProposalLimitReached);
+            }
+            Ok(())
+        }
+
+        /// Verifica que el valor transferido cubra el depósito de `count` propuestas.
+        fn ensure_deposit(&self, count: u32) -> Result<(), Error> {
+            let required = self.settings.proposal_deposit.saturating_mul(Balance::from(count));
+            if self.env().transferred_value() < required {
+                return Err(Error::InsufficientDeposit);
+            }
+            Ok(())
+        }
+
+        /// Estado de la propuesta considerando el plazo: una propuesta activa vencida está cerrada.
+        /// Una votación secreta sigue activa, aunque se haya cerrado, hasta que termina su fase de
+        /// revelación, para que nadie la finalice, vete o ejecute antes de contar los votos.
+        fn effective_status(&self, proposal: &Proposal) -> ProposalStatus {
+            if matches!(proposal.status, ProposalStatus::Active | ProposalStatus::Closed)
+                && self.reveal_pending(proposal)
+            {
+                return ProposalStatus::Active;
+            }
+            if proposal.status == ProposalStatus::Active
+                && self.env().block_number() >= proposal.end_block
+            {
+                return ProposalStatus::Closed;
+            }
+            proposal.status
+        }
+
+        /// Indica si una votación secreta todavía no terminó su fase de revelación.
+        fn reveal_pending(&self, proposal: &Proposal) -> bool {
+            proposal.reveal_block.is_some_and(|block| {
+                self.env().block_number() < block.saturating_add(self.settings.reveal_period)
+            })
+        }
+
+        /// Indica si una propuesta alcanzó el quórum y el umbral de aprobación.
+        fn outcome(proposal: &Proposal) -> bool {
+            proposal.quorum_met() && proposal.meets_pass_rule()
+        }
+
+        /// Congela el resultado de la propuesta, la marca como finalizada y emite el evento.
+        fn freeze_result(&mut self, proposal_id: u32, proposal: &mut Proposal) {
+            proposal.final_votes = proposal.yes_votes;
+            proposal.passed = Self::outcome(proposal);
+            self.set_status(proposal, ProposalStatus::Finalized);
+            self.env().emit_event(ProposalFinalized {
+                id: proposal_id,
+                final_votes: proposal.final_votes,
+                passed: proposal.passed,
+            });
+        }
+
+        /// Quita `proposal_id` del historial de votos de `voter`, moviendo la última entrada a su lugar.
+        fn forget_vote(&mut self, proposal_id: u32, voter: AccountId) {
+            let Some(position) = self.history_positions.take((voter, proposal_id)) else {
+                return;
+            };
+            let last = self.voting_history_count(voter).saturating_sub(1);
+            if let Some(moved) = self.voting_history.take((voter, last)) {
+                if position != last {
+                    self.voting_history.insert((voter, position), &moved);
+                    self.history_positions.insert((voter, moved), &position);
+                }
+            }
+            self.history_counts.insert(voter, &last);
+        }
+
+        /// Cantidad de votantes registrados en `proposal_voters` para una propuesta.
+        fn voter_count(&self, proposal_id: u32) -> u32 {
+            self.voter_counts.get(proposal_id).unwrap_or(0)
+        }
+
+        /// Quita a `voter` de la lista de votantes de una propuesta, moviendo al último a su lugar.
+        fn drop_voter(&mut self, proposal_id: u32, voter: AccountId) {
+            let Some(position) = self.voter_positions.take((proposal_id, voter)) else {
+                return;
+            };
+            let last = self.voter_count(proposal_id).saturating_sub(1);
+            if let Some(moved) = self.proposal_voters.take((proposal_id, last)) {
+                if position != last {
+                    self.proposal_voters.insert((proposal_id, position), &moved);
+                    self.voter_positions.insert((proposal_id, moved), &position);
+                }
+            }
+            self.voter_counts.insert(proposal_id, &last);
+        }
+
+        /// Cantidad de cuentas que delegaron directamente en `delegate`.
+        fn delegator_count(&self, delegate: AccountId) -> u32 {
+            self.delegator_counts.get(delegate).unwrap_or(0)
+        }
+
+        /// Cantidad de propuestas existentes con `status` guardado.
+        fn status_count(&self, status: ProposalStatus) -> u32 {
+            self.status_counts.get(status).unwrap_or(0)
+        }
+
+        /// Cambia el estado guardado de una propuesta y actualiza los contadores por estado.
+        fn set_status(&mut self, proposal: &mut Proposal, status: ProposalStatus) {
+            let previous = self.status_count(proposal.status).saturating_sub(1);
+            self.status_counts.insert(proposal.status, &previous);
+            let next = self.status_count(status).saturating_add(1);
+            self.status_counts.insert(status, &next);
+            proposal.status = status;
+        }
+
+        /// Incrementa el nonce de `account` tras una acción que modifica su participación.
+        fn bump_nonce(&mut self, account: AccountId) {
+            let nonce = self.nonce_of(account).saturating_add(1);
+            self.nonces.insert(account, &nonce);
+        }
+
+        /// Verifica que los votos no estén pausados.
+        fn ensure_voting_not_paused(&self) -> Result<(), Error> {
+            if self.voting_paused {
+                return Err(Error::VotingPaused);
+            }
+            Ok(())
+        }
+
+        /// Verifica que la creación de propuestas no esté pausada.
+        fn ensure_creation_not_paused(&self) -> Result<(), Error> {
+            if self.creation_paused {
+                return Err(Error::CreationPaused);
+            }
+            Ok(())
+        }
+
+        /// Verifica que `voter` pueda votar en la propuesta: que siga abierta, que no esté bloqueado,
+        /// que esté habilitado, que no haya votado ni sumado su peso en el voto de un delegado, que
+        /// no haya delegado su voto y que tenga el
+        /// saldo de token exigido. Si la propuesta tiene raíz de elegibilidad, la habilitación se
+        /// demuestra con `proof`.
+        fn ensure_can_vote(
+            &mut self,
+            proposal_id: u32,
+            proposal: &Proposal,
+            voter: AccountId,
+            proof: Option<&[[u8; 32]]>,
+        ) -> Result<(), Error> {
+            self.check_can_vote(proposal_id, proposal, voter, proof)?;
+            // El saldo se verifica al final para no llamar al token si otra condición ya falla
+            self.ensure_token_balance(proposal_id, proposal.snapshot_block, voter)
+        }
+
+        /// Igual que `ensure_can_vote`, sin verificar el saldo del token de gobernanza.
+        fn check_can_vote(
+            &self,
+            proposal_id: u32,
+            proposal: &Proposal,
+            voter: AccountId,
+            proof: Option<&[[u8; 32]]>,
+        ) -> Result<(), Error> {
+            self.ensure_open(proposal)?;
+            self.ensure_not_blacklisted(voter)?;
+            match proposal.eligibility_root {
+                Some(root) => {
+                    if !proof.is_some_and(|proof| self.verify_proof(root, voter, proof)) {
+                        return Err(Error::InvalidProof);
+                    }
+                }
+                None => {
+                    if !self.is_eligible(voter) {
+                        return Err(Error::NotEligible);
+                    }
+                }
+            }
+            if !self.settings.owner_can_vote && voter == self.owner {
+                return Err(Error::OwnerCannotVote);
+            }
+            if !self.settings.creator_can_vote && voter == proposal.creator {
+                return Err(Error::CreatorCannotVote);
+            }
+            if self.reputation_of(voter) < proposal.min_reputation {
+                return Err(Error::InsufficientReputation);
+            }
+            if self.has_voted_or_carried(proposal_id, voter) {
+                return Err(Error::AlreadyVoted);
+            }
+            if self.active_delegate(voter).is_some() {
+                return Err(Error::VoteDelegated);
+            }
+            Ok(())
+        }
+
+        /// Retorna el peso asignado a `voter`, o `ZeroWeight` si es 0.
+        fn ensure_weight(&self, voter: AccountId) -> Result<u32, Error> {
+            match self.weight_of(voter) {
+                0 => Err(Error::ZeroWeight),
+                weight => Ok(weight),
+            }
+        }
+
+        /// Verifica una prueba de Merkle de `voter` contra `root`. La hoja es el hash de la cuenta
+        /// y cada nivel hashea el par ordenado de menor a mayor, por lo que la prueba no necesita
+        /// indicar de qué lado va cada hermano.
+        fn verify_proof(&self, root: [u8; 32], voter: AccountId, proof: &[[u8; 32]]) -> bool {
+            let leaf: &[u8; 32] = voter.as_ref();
+            let mut node = self.env().hash_bytes::<Blake2x256>(leaf);
+            for sibling in proof {
+                let (first, second) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+                let mut pair = [0u8; 64];
+                pair[..32].copy_from_slice(&first);
+                pair[32..].copy_from_slice(&second);
+                node = self.env().hash_bytes::<Blake2x256>(&pair);
+            }
+            node == root
+        }
+
+        /// Valida y registra un voto directo de `voter`. En modo ponderado el peso base es
+        /// `contribution`, que queda guardado para reembolsarlo; si no, es el peso asignado.
+        fn cast_vote(
+            &mut self,
+            proposal_id: u32,
+            voter: AccountId,
+            choice: VoteChoice,
+            contribution: Balance,
+            proof: Option<&[[u8; 32]]>,
+        ) -> Result<(), Error> {
+            // Verificar existencia 
+            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalDoesNotExist)?;
+
+            if proposal.reveal_block.is_some() {
+                return Err(Error::WrongVotingMode);
+            }
+            proposal.validate_choice(choice)?;
+            self.ensure_can_vote(proposal_id, &proposal, voter, proof)?;
+            self.ensure_cooldown(voter)?;
+
+            // Registrar voto en el contador de la opción elegida
+            let base_weight = if self.settings.value_weighted {
+                Self::tally_weight(contribution)
+            } else {
+                self.ensure_weight(voter)?
+            };
+            self.record_vote(proposal_id, &mut proposal, voter, choice, base_weight)?;
+            if contribution > 0 {
+                self.contributions.insert((proposal_id, voter), &contribution);
+                self.locked_funds = self.locked_funds.saturating_add(contribution);
+            }
+            let block = self.env().block_number();
+            self.last_vote_block.insert(voter, &block);
+
+            Ok(())
+        }
+
+        /// Suma el voto al conteo con `base_weight` más el peso delegado, limitado al tope de la
+        /// propuesta, lo registra y emite el evento.
+        /// Falla si la cadena de delegación que llega a `voter` es demasiado profunda.
+        fn record_vote(
+            &mut self,
+            proposal_id: u32,
+            proposal: &mut Proposal,
+            voter: AccountId,
+            choice: VoteChoice,
+            base_weight: u32,
+        ) -> Result<(), Error> {
+            // Con raíz de elegibilidad o reputación mínima no se comprueba a los delegantes, así
+            // que no suman
+            let mut counted = Vec::new();
+            let delegated = if proposal.eligibility_root.is_some() || proposal.min_reputation > 0 {
+                0
+            } else {
+                self.delegated_weight(Some(proposal_id), voter, voter, 0, &mut counted)?
+            };
+            let weight = self.applied_weight(proposal, base_weight.saturating_add(delegated));
+            self.tally_vote(proposal_id, proposal, voter, choice, weight);
+            self.carry_delegators(proposal_id, voter, counted);
+            self.close_on_quorum(proposal_id, proposal);
+            Ok(())
+        }
+
+        /// Peso que suma un voto de `weight`, en unidades de los contadores: aplica el decaimiento
+        /// y luego el tope por votante de la propuesta.
+        fn applied_weight(&self, proposal: &Proposal, weight: u32) -> u32 {
+            let weight = self.decayed_weight(proposal, weight);
+            if proposal.max_weight_per_voter == 0 {
+                return weight;
+            }
+            let cap = proposal.in_units(proposal.max_weight_per_voter);
+            weight.min(u32::try_from(cap).unwrap_or(u32::MAX))
+        }
+
+        /// Cierra la votación si la propuesta lo pide al alcanzar el quórum y ya lo alcanzó.
+        fn close_on_quorum(&mut self, proposal_id: u32, proposal: &mut Proposal) {
+            if !proposal.auto_close_on_quorum
+                || proposal.status != ProposalStatus::Active
+                || !proposal.quorum_met()
+            {
+                return;
+            }
+            self.set_status(proposal, ProposalStatus::Closed);
+            self.proposals.insert(proposal_id, proposal);
+            self.env().emit_event(ProposalClosed {
+                id: proposal_id,
+                total_votes: proposal.total_votes(),
+            });
+        }
+
+        /// Suma `weight` a la opción elegida, registra al votante y emite el evento.
+        fn tally_vote(
+            &mut self,
+            proposal_id: u32,
+            proposal: &mut Proposal,
+            voter: AccountId,
+            choice: VoteChoice,
+            weight: u32,
+        ) {
+            proposal.add_votes(choice, weight);
+            self.proposals.insert(proposal_id, proposal);
+            self.voters.insert((proposal_id, voter), &choice);
+            self.vote_weights.insert((proposal_id, voter), &weight);
+            let position = self.voter_count(proposal_id);
+            self.proposal_voters.insert((proposal_id, position), &voter);
+            self.voter_positions.insert((proposal_id, voter), &position);
+            self.voter_counts.insert(proposal_id, &position.saturating_add(1));
+            self.total_votes_cast = self.total_votes_cast.saturating_add(1);
+            if !self.participants.contains(voter) {
+                self.participants.insert(voter, &true);
+                self.unique_voters = self.unique_voters.saturating_add(1);
+            }
+            let position = self.voting_history_count(voter);
+            self.voting_history.insert((voter, position), &proposal_id);
+            self.history_positions.insert((voter, proposal_id), &position);
+            self.history_counts.insert(voter, &position.saturating_add(1));
+            self.bump_nonce(voter);
+
+            // Emitir evento 
+            if choice == VoteChoice::Downvote {
+                self.env().emit_event(DownvoteCast { proposal_id, voter });
+            } else {
+                self.env().emit_event(VoteCast {
+                    proposal_id,
+                    voter,
+                    choice,
+                    new_total: proposal.votes_for(choice),
+                    weight,
+                    timestamp: self.env().block_timestamp(),
+                });
+            }
+        }
+
+        /// En modo de decaimiento escala `weight` por la fracción de la votación que queda por
+        /// delante: `weight * DECAY_SCALE * restantes / duración`, con un mínimo de 1. Los votos
+        /// revelados después del plazo conservan su peso completo, `weight * DECAY_SCALE`.
+        fn decayed_weight(&self, proposal: &Proposal, weight: u32) -> u32 {
+            let now = self.env().block_number();
+            if !self.settings.decay_mode || weight == 0 {
+                return weight;
+            }
+            if now >= proposal.end_block {
+                let scaled = u64::from(weight).saturating_mul(DECAY_SCALE);
+                return u32::try_from(scaled).unwrap_or(u32::MAX);
+            }
+            let window = u64::from(proposal.end_block.saturating_sub(proposal.start_block)).max(1);
+            let remaining = u64::from(proposal.end_block - now);
+            let scaled = u64::from(weight)
+                .saturating_mul(DECAY_SCALE)
+                .saturating_mul(remaining)
+                / window;
+            u32::try_from(scaled).unwrap_or(u32::MAX).max(1)
+        }
+
+        /// Verifica que la propuesta acepte votos: activa, con la votación ya comenzada y dentro
+        /// de su plazo.
+        fn ensure_open(&self, proposal: &Proposal) -> Result<(), Error> {
+            self.ensure_not_finished(proposal)?;
+            if self.env().block_number() < proposal.start_block {
+                return Err(Error::VotingNotStarted);
+            }
+            Ok(())
+        }
+
+        /// Verifica que la propuesta esté activa y que su plazo no haya vencido, aunque la votación
+        /// todavía no haya comenzado.
+        fn ensure_not_finished(&self, proposal: &Proposal) -> Result<(), Error> {
+            match proposal.status {
+                ProposalStatus::Active => {}
+                ProposalStatus::Closed => return Err(Error::ProposalClosed),
+                ProposalStatus::Cancelled => return Err(Error::ProposalCancelled),
+                ProposalStatus::Finalized => return Err(Error::AlreadyFinalized),
+                _ => return Err(Error::ProposalNotActive),
+            }
+            if self.env().block_number() >= proposal.end_block {
+                return Err(Error::VotingClosed);
+            }
+            Ok(())
+        }
+
+        /// Verifica que `account` no esté bloqueada.
+        fn ensure_not_blacklisted(&self, account: AccountId) -> Result<(), Error> {
+            if self.is_blacklisted(account) {
+                return Err(Error::AccountBlacklisted);
+            }
+            Ok(())
+        }
+
+        /// Verifica que hayan pasado `settings.vote_cooldown` bloques desde el último voto de `voter`.
+        fn ensure_cooldown(&self, voter: AccountId) -> Result<(), Error> {
+            if self.settings.vote_cooldown == 0 {
+                return Ok(());
+            }
+            if let Some(last) = self.last_vote_block.get(voter) {
+                if self.env().block_number().saturating_sub(last) < self.settings.vote_cooldown {
+                    return Err(Error::VotingTooSoon);
+                }
+            }
+            Ok(())
+        }
+
+        /// Verifica que `voter` alcance el saldo mínimo del token de gobernanza en el bloque de
+        /// instantánea de la propuesta. No hace nada si no hay token configurado.
+        /// El saldo se mide al crear la propuesta y no al votar para que no sirva pedir tokens
+        /// prestados (por ejemplo con un préstamo flash) justo antes de votar y devolverlos después.
+        /// El saldo de los delegadores no se verifica para no multiplicar las llamadas externas.
+        fn ensure_token_balance(&mut self, proposal_id: u32, snapshot_block: u32, voter: AccountId) -> Result<(), Error> {
+            if let Some(balance) = self.token_balance(proposal_id, snapshot_block, voter)? {
+                if !self.token_balances.contains((proposal_id, voter)) {
+                    self.token_balances.insert((proposal_id, voter), &balance);
+                }
+            }
+            Ok(())
+        }
+
+        /// Saldo de `voter` en el bloque de instantánea, ya resuelto o consultado al token, si
+        /// alcanza el mínimo. Retorna `None` si no hay token configurado. No guarda el resultado.
+        fn token_balance(&self, proposal_id: u32, snapshot_block: u32, voter: AccountId) -> Result<Option<Balance>, Error> {
+            let Some(token) = self.settings.governance_token else {
+                return Ok(None);
+            };
+            let balance = match self.token_balances.get((proposal_id, voter)) {
+                Some(balance) => balance,
+                None => Self::balance_at(token, voter, snapshot_block)?,
+            };
+            if balance < self.settings.min_token_balance {
+                return Err(Error::InsufficientTokenBalance);
+            }
+            Ok(Some(balance))
+        }
+
+        /// Consulta `PSP22Snapshot::balance_of_at` en el token de gobernanza. Si el token no
+        /// implementa el mensaje retorna `SnapshotUnsupported`.
+        fn balance_at(token: AccountId, account: AccountId, block: u32) -> Result<Balance, Error> {
+            let balance = build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("PSP22Snapshot::balance_of_at")))
+                        .push_arg(account)
+                        .push_arg(block),
+                )
+                .returns::<Balance>()
+                .try_invoke();
+            match balance {
+                Ok(Ok(balance)) => Ok(balance),
+                Ok(Err(_)) => Err(Error::SnapshotUnsupported),
+                Err(_) => Err(Error::TokenCallFailed),
+            }
+        }
+
+        /// Peso asignado de cuentas habilitadas que delegaron en `delegate`, directamente o a través
+        /// de una cadena, y no votaron por su cuenta en la propuesta (si se indica una) ni sumaron
+        /// ya en el voto de otro delegado. Agrega a `counted` cada delegante cuyo peso suma.
+        /// `depth` es la cantidad de saltos ya recorridos hasta `delegate` desde `root`.
+        fn delegated_weight(
+            &self,
+            proposal_id: Option<u32>,
+            root: AccountId,
+            delegate: AccountId,
+            depth: u32,
+            counted: &mut Vec<AccountId>,
+        ) -> Result<u32, Error> {
+            let count = self.delegator_count(delegate);
+            if count > 0 && depth >= MAX_DELEGATION_DEPTH {
+                return Err(Error::DelegationTooDeep);
+            }
+            let mut weight: u32 = 0;
+            for position in 0..count {
+                let Some(delegator) = self.delegators.get((delegate, position)) else {
+                    continue;
+                };
+                if self.active_delegate(delegator) != Some(delegate) {
+                    continue;
+                }
+                if delegator == root {
+                    return Err(Error::DelegationCycle);
+                }
+                let voted = proposal_id.is_some_and(|id| self.has_voted_or_carried(id, delegator));
+                if voted || !self.is_eligible(delegator) {
+                    continue;
+                }
+                counted.push(delegator);
+                let chained =
+                    self.delegated_weight(proposal_id, root, delegator, depth.saturating_add(1), counted)?;
+                weight = weight.saturating_add(self.weight_of(delegator)).saturating_add(chained);
+            }
+            Ok(weight)
+        }
+
+        /// Indica si `account` votó en la propuesta o si su peso ya sumó en el voto vigente de un
+        /// delegado, aunque después haya retirado la delegación o esta haya vencido.
+        fn has_voted_or_carried(&self, proposal_id: u32, account: AccountId) -> bool {
+            self.voters.contains((proposal_id, account))
+                || self
+                    .carried_by
+                    .get((proposal_id, account))
+                    .is_some_and(|delegate| self.voters.contains((proposal_id, delegate)))
+        }
+
+        /// Registra que el voto de `delegate` en la propuesta sumó el peso de `delegators`.
+        fn carry_delegators(&mut self, proposal_id: u32, delegate: AccountId, delegators: Vec<AccountId>) {
+            let mut count = self.carried_counts.get((proposal_id, delegate)).unwrap_or(0);
+            for delegator in delegators {
+                self.carried_by.insert((proposal_id, delegator), &delegate);
+                self.carried_delegators.insert((proposal_id, delegate, count), &delegator);
+                count = count.saturating_add(1);
+            }
+            if count > 0 {
+                self.carried_counts.insert((proposal_id, delegate), &count);
+            }
+        }
+
+        /// Libera a los delegantes cuyo peso sumó el voto de `delegate` en la propuesta, que
+        /// vuelven a poder votar directamente o a sumar en otro voto.
+        fn release_delegators(&mut self, proposal_id: u32, delegate: AccountId) {
+            for position in 0..self.carried_counts.take((proposal_id, delegate)).unwrap_or(0) {
+                if let Some(delegator) = self.carried_delegators.take((proposal_id, delegate, position)) {
+                    self.carried_by.remove((proposal_id, delegator));
+                }
+            }
+        }
+
+        /// Cuenta en la que delegó `account`, si su delegación sigue vigente.
+        fn active_delegate(&self, account: AccountId) -> Option<AccountId> {
+            self.get_delegate(account).map(|(delegate, _)| delegate)
+        }
+
+        /// Elimina la delegación de una cuenta, vigente o vencida, si existe.
+        fn remove_delegation(&mut self, delegator: AccountId) {
+            let Some((previous, _)) = self.delegations.take(delegator) else {
+                return;
+            };
+            let Some(position) = self.delegator_positions.take(delegator) else {
+                return;
+            };
+            let last = self.delegator_count(previous).saturating_sub(1);
+            if let Some(moved) = self.delegators.take((previous, last)) {
+                if position != last {
+                    self.delegators.insert((previous, position), &moved);
+                    self.delegator_positions.insert(moved, &position);
+                }
+            }
+            self.delegator_counts.insert(previous, &last);
+        }
+
+        /// Convierte un peso a la escala de los contadores, saturando en `u32::MAX`.
+        fn tally_weight(weight: Balance) -> u32 {
+            u32::try_from(weight).unwrap_or(u32::MAX)
+        }
+
+        /// Ejecuta `body` con el cerrojo de reentrada tomado y lo libera al terminar.
+        /// El struct raíz solo se persiste al final del mensaje, así que el cerrojo se escribe de
+        /// inmediato en su celda `Lazy`: una llamada anidada lo lee tomado desde el storage.
+        /// Envuelve todos los mensajes que mueven fondos, registran votos (pueden consultar el
+        /// token de gobernanza) o ejecutan acciones; las consultas de solo lectura quedan exentas.
+        fn non_reentrant<T>(
+            &mut self,
+            body: impl FnOnce(&mut Self) -> Result<T, Error>,
+        ) -> Result<T, Error> {
+            if self.locked.get().unwrap_or(false) {
+                return Err(Error::Reentrancy);
+            }
+            self.locked.set(&true);
+            let result = body(self);
+            self.locked.set(&false);
+            result
+        }
+
+        /// Verifica que el caller sea el owner del contrato.
+        /// En modo multifirma ningún owner actúa por sí solo y retorna `NotEnoughApprovals`: la
+        /// acción debe proponerse como `GovernanceAction` y ejecutarse con `execute_action`.
+        fn ensure_owner(&self) -> Result<(), Error> {
+            if !self.is_owner(self.env().caller()) {
+                return Err(Error::OnlyOwnerCanPerformAction);
+            }
+            self.ensure_single_signer()
+        }
+
+        /// Indica si `account` es el owner actual. Tras la renuncia nadie lo es, ni siquiera la
+        /// cuenta nula que queda guardada.
+        fn is_owner(&self, account: AccountId) -> bool {
+            account == self.owner && !self.is_zero(account)
+        }
+
+        /// Reemplaza al owner, descarta la transferencia pendiente, registra el cambio en el
+        /// historial y emite el evento.
+        fn change_owner(&mut self, new: AccountId) {
+            let previous = self.owner;
+            self.owner = new;
+            self.pending_owner = None;
+            self.owner_history.insert(self.owner_history_len, &(new, self.env().block_timestamp()));
+            self.owner_history_len = self.owner_history_len.saturating_add(1);
+            self.env().emit_event(OwnershipTransferred { previous, new });
+        }
+
+        /// Cuenta nula, usada para representar la ausencia de owner.
+        fn zero_account() -> AccountId {
+            AccountId::from([0u8; 32])
+        }
+
+        /// Verifica que el contrato no esté en modo multifirma, en el que las acciones privilegiadas
+        /// solo se ejecutan mediante `execute_action`.
+        fn ensure_single_signer(&self) -> Result<(), Error> {
+            if self.settings.required_approvals > 1 {
+                return Err(Error::NotEnoughApprovals);
+            }
+            Ok(())
+        }
+
+        /// Verifica que el caller sea uno de los owners multifirma.
+        fn ensure_governor(&self) -> Result<(), Error> {
+            if !self.settings.owners.contains(&self.env().caller()) {
+                return Err(Error::OnlyOwnerCanPerformAction);
+            }
+            Ok(())
+        }
+
+        /// Verifica que el caller sea el owner o un moderador. Como `ensure_owner`, queda bloqueado
+        /// en modo multifirma.
+        fn ensure_moderator(&self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if !self.is_owner(caller) && !self.is_moderator(caller) {
+                return Err(Error::Unauthorized);
+            }
+            self.ensure_single_signer()
+        }
+    }
+
+    /// =========================
+    /// TESTS
+    /// =========================
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::test;
+
+        /// Función auxiliar para simular quién firma la transacción
+        fn set_caller(account: AccountId) {
+            test::set_caller::<ink::env::DefaultEnvironment>(account);
+        }
+
+        /// Función auxiliar con los parámetros por defecto y la duración indicada
+        fn params(duration_blocks: u32) -> ProposalParams {
+            ProposalParams {
+                duration_blocks,
+                ..Default::default()
+            }
+        }
+
+        #[ink::test]
+        fn test_inicializacion_y_acceso_owner() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            
+            //  Verificar inicializacion
+            assert_eq!(contract.total_proposals(), 0);
+
+            // Verificar control de acceso para el owner 
+            set_caller(accounts.bob); // Bob intenta crear
+            let res = contract.create_proposal(String::from("Falla"), params(100));
+            assert_eq!(res, Err(Error::OnlyOwnerCanPerformAction));
+        }
+
+        #[ink::test]
+        fn test_creacion_propuestas_y_consulta() {
+            // Crear contrato y establecer caller como owner
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice); // Alice es owner por defecto en el constructor
+
+            // Crear multiples propuestas 
+            assert_eq!(contract.create_proposal(String::from("Propuestarda0"), params(100)), Ok(0));
+            assert_eq!(contract.create_proposal(String::from("Propuestarda00"), params(100)), Ok(1));
+            // Verificar conteo de propuestas
+            assert_eq!(contract.total_proposals(), 2);
+
+            // Verificar datos públicos 
+            let proposal = contract.get_proposal(0).unwrap();
+            assert_eq!(proposal.title, "Propuestarda0");
+            assert_eq!(proposal.yes_votes, 0);
+            assert_eq!(proposal.no_votes, 0);
+            assert_eq!(proposal.abstain_votes, 0);
+            assert_eq!(proposal.status, ProposalStatus::Active);
+            assert_eq!(proposal.end_block, 100);
+            assert_eq!(proposal.creator, accounts.alice);
+
+            // La version basica mantiene la tupla anterior
+            let basica = contract.get_proposal_basic(1).unwrap();
+            assert_eq!(basica.0, "Propuestarda00"); // .0 es el titulo
+            assert_eq!(basica.1, 0);             // .1 son los votos a favor iniciales
+            assert_eq!(basica.2, 0);             // .2 son los votos en contra iniciales
+            assert_eq!(basica.3, 0);             // .3 son las abstenciones iniciales
+        }
+
+        #[ink::test]
+        fn test_titulo_y_descripcion() {
+            let mut contract = VotingSystem::new();
+            set_caller(test::default_accounts::<ink::env::DefaultEnvironment>().alice);
+            contract
+                .create_proposal_with_description(
+                    String::from("Presupuesto"),
+                    String::from("Asignación anual de fondos"),
+                    params(100),
+                )
+                .unwrap();
+            contract.create_proposal(String::from("Sin detalle"), params(100)).unwrap();
+
+            // Título y descripción se guardan por separado
+            let ProposalView { title, description, .. } = contract.get_proposal(0).unwrap();
+            assert_eq!((title.as_str(), description.as_str()), ("Presupuesto", "Asignación anual de fondos"));
+            assert_eq!(contract.get_proposal(1).unwrap().description, "");
+            let larga = "x".repeat(MAX_DESCRIPTION_LEN as usize + 1);
+            assert_eq!(
+                contract.create_proposal_with_description(String::from("Larga"), larga, params(100)),
+                Err(Error::DescriptionTooLong)
+            );
+        }
+
+        #[ink::test]
+        fn test_correccion_de_la_descripcion() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract
+                .create_proposal_with_description(String::from("Obra"), String::from("Versión 1"), params(100))
+                .unwrap();
+            assert_eq!(contract.get_edit_count(0), Ok(0));
+            assert_eq!(contract.get_edit_history(0, 0, 10), Ok(vec![]));
+
+            // Solo el creador corrige, y cada versión anterior queda en el historial
+            set_caller(accounts.bob);
+            assert_eq!(contract.amend_proposal(0, String::from("Ajena")), Err(Error::Unauthorized));
+            set_caller(accounts.alice);
+            contract.amend_proposal(0, String::from("Versión 2")).unwrap();
+            contract.amend_proposal(0, String::from("Versión 3")).unwrap();
+            assert_eq!(contract.get_proposal(0).unwrap().description, "Versión 3");
+            assert_eq!(contract.get_edit_count(0), Ok(2));
+            assert_eq!(
+                contract.get_edit_history(0, 0, 10),
+                Ok(vec![String::from("Versión 1"), String::from("Versión 2")])
+            );
+            assert_eq!(contract.get_edit_history(0, 1, 1), Ok(vec![String::from("Versión 2")]));
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let last = events.last().unwrap();
+            let amended = <ProposalAmended as ink::scale::Decode>::decode(&mut &last.data[..]).unwrap();
+            assert_eq!((amended.id, amended.edits), (0, 2));
+
+            // Con votos registrados ya no se puede corregir
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            set_caller(accounts.alice);
+            assert_eq!(contract.amend_proposal(0, String::from("Tarde")), Err(Error::ProposalHasVotes));
+            assert_eq!(contract.get_edit_count(99), Err(Error::ProposalDoesNotExist));
+            assert_eq!(contract.get_edit_history(99, 0, 10), Err(Error::ProposalDoesNotExist));
+        }
+
+        #[ink::test]
+        fn test_validacion_de_titulo() {
+            let mut contract = VotingSystem::new_with_limits(8);
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+
+            // Titulo vacio
+            assert_eq!(contract.create_proposal(String::new(), params(100)), Err(Error::EmptyTitle));
+            // Exactamente en el limite se acepta
+            assert_eq!(contract.create_proposal(String::from("12345678"), params(100)), Ok(0));
+            // Un byte de mas se rechaza
+            assert_eq!(
+                contract.create_proposal(String::from("123456789"), params(100)),
+                Err(Error::TitleTooLong)
+            );
+            // El limite se aplica tambien a los lotes
+            let lote = Vec::from([String::from("ok"), String::new()]);
+            assert_eq!(contract.create_proposals(lote, params(100)), Err(Error::EmptyTitle));
+            assert_eq!(contract.total_proposals(), 1);
+        }
+
+        #[ink::test]
+        fn test_metadatos_fuera_de_cadena() {
+            let mut contract = VotingSystem::new_with_limits(16);
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let cid = String::from("ipfs://bafy123");
+            let with_metadata = ProposalParams {
+                metadata_uri: cid.clone(),
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Con metadatos"), with_metadata).unwrap();
+            contract.create_proposal(String::from("Sin metadatos"), params(100)).unwrap();
+            assert_eq!(contract.get_metadata(0), Ok(cid.clone()));
+            assert_eq!(contract.get_metadata(1), Ok(String::new()));
+            assert_eq!(contract.get_metadata(99), Err(Error::ProposalDoesNotExist));
+
+            // La URI se publica en el evento de creación, que sigue al de despliegue
+            let first = test::recorded_events().nth(1).unwrap();
+            let event = <ProposalCreated as ink::scale::Decode>::decode(&mut &first.data[..]).unwrap();
+            assert_eq!(event.metadata_uri, cid);
+            assert_eq!(event.creator, accounts.alice);
+
+            // El creador se publica como topic para poder indexarlo
+            let alice: &[u8; 32] = accounts.alice.as_ref();
+            assert!(first.topics.iter().any(|topic| topic[..] == alice[..]));
+
+            // La URI respeta el límite de longitud del título
+            let too_long = ProposalParams {
+                metadata_uri: String::from("ipfs://una-uri-demasiado-larga"),
+                ..params(100)
+            };
+            assert_eq!(
+                contract.create_proposal(String::from("Larga"), too_long),
+                Err(Error::MetadataTooLong)
+            );
+        }
+
+        #[ink::test]
+        fn test_titulos_duplicados() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                dedupe_titles: true,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Unica"), params(100)).unwrap();
+
+            // Un titulo en uso se rechaza, tambien dentro de un lote
+            assert_eq!(
+                contract.create_proposal(String::from("Unica"), params(100)),
+                Err(Error::DuplicateTitle)
+            );
+            let lote = Vec::from([String::from("Otra"), String::from("Otra")]);
+            assert_eq!(contract.create_proposals(lote, params(100)), Err(Error::DuplicateTitle));
+
+            // Eliminar la propuesta libera el titulo
+            contract.delete_proposal(0).unwrap();
+            assert_eq!(contract.create_proposal(String::from("Unica"), params(100)), Ok(1));
+
+            // Sin la opcion activa se permiten duplicados
+            let mut permisivo = VotingSystem::new();
+            permisivo.create_proposal(String::from("Igual"), params(100)).unwrap();
+            assert!(permisivo.create_proposal(String::from("Igual"), params(100)).is_ok());
+        }
+
+        #[ink::test]
+        fn test_deposito_y_reembolso() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                proposal_deposit: 100,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contrato = ink::env::account_id::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+
+            // Sin el deposito suficiente no se crea la propuesta
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(99);
+            assert_eq!(
+                contract.create_proposal(String::from("Sin deposito"), params(10)),
+                Err(Error::InsufficientDeposit)
+            );
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            contract.create_proposal(String::from("Con deposito"), params(10)).unwrap();
+            test::set_account_balance::<ink::env::DefaultEnvironment>(contrato, 1_000_100);
+
+            // El reembolso solo procede al terminar la votacion
+            assert_eq!(contract.refund_deposit(0), Err(Error::VotingStillOpen));
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            let saldo_previo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice).unwrap();
+            assert!(contract.refund_deposit(0).is_ok());
+            let saldo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice).unwrap();
+            assert_eq!(saldo, saldo_previo + 100);
+
+            // No se puede reembolsar dos veces
+            assert_eq!(contract.refund_deposit(0), Err(Error::NothingToRefund));
+        }
+
+        #[ink::test]
+        fn test_retiro_de_tesoreria() {
+            // El contrato usa una cuenta propia para distinguir sus fondos de los del owner
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                proposal_deposit: 100,
+                ..Default::default()
+            });
+            let contrato = ink::env::account_id::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(150);
+            contract.create_proposal(String::from("Con excedente"), params(10)).unwrap();
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            test::set_account_balance::<ink::env::DefaultEnvironment>(contrato, 1_000_150);
+
+            // El depósito de 100 sigue retenido hasta su reembolso
+            assert_eq!(contract.treasury_balance(), 1_000_050);
+            assert_eq!(
+                contract.withdraw_treasury(1_000_051, accounts.frank),
+                Err(Error::InsufficientTreasury)
+            );
+            set_caller(accounts.bob);
+            assert_eq!(contract.withdraw_treasury(50, accounts.bob), Err(Error::OnlyOwnerCanPerformAction));
+            set_caller(accounts.alice);
+            let saldo_previo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.frank).unwrap();
+            assert!(contract.withdraw_treasury(50, accounts.frank).is_ok());
+            let saldo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.frank).unwrap();
+            assert_eq!(saldo, saldo_previo + 50);
+            assert_eq!(contract.treasury_balance(), 1_000_000);
+
+            // Al eliminar la propuesta su depósito vuelve al creador, no a la tesorería
+            let saldo_previo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice).unwrap();
+            contract.delete_proposal(0).unwrap();
+            let saldo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice).unwrap();
+            assert_eq!(saldo, saldo_previo + 100);
+            assert_eq!(contract.treasury_balance(), 1_000_000);
+        }
+
+        fn set_proposal_count(contract: &mut VotingSystem, count: u32) {
+            contract.proposal_count = count;
+        }
+
+        #[ink::test]
+        fn test_desborde_del_contador_de_propuestas() {
+            let mut contract = VotingSystem::new();
+            set_caller(test::default_accounts::<ink::env::DefaultEnvironment>().alice);
+            set_proposal_count(&mut contract, u32::MAX - 1);
+
+            // El último ID disponible se asigna y luego la creación falla sin repetir IDs
+            assert_eq!(contract.create_proposal(String::from("Última"), params(100)), Ok(u32::MAX - 1));
+            assert_eq!(
+                contract.create_proposal(String::from("Sin ID"), params(100)),
+                Err(Error::ProposalCounterOverflow)
+            );
+            assert_eq!(
+                contract.create_proposals(vec![String::from("Lote")], params(100)),
+                Err(Error::ProposalCounterOverflow)
+            );
+            assert_eq!(contract.total_proposals(), u32::MAX);
+        }
+
+        #[ink::test]
+        fn test_limite_de_propuestas() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                max_proposals: 2,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            assert_eq!(contract.remaining_slots(), 2);
+            contract.create_proposal(String::from("Primera"), params(100)).unwrap();
+            assert_eq!(contract.remaining_slots(), 1);
+
+            // Un lote que supera la capacidad se rechaza completo
+            let titles = vec![String::from("A"), String::from("B")];
+            assert_eq!(contract.create_proposals(titles, params(100)), Err(Error::ProposalLimitReached));
+            contract.create_proposal(String::from("Segunda"), params(100)).unwrap();
+            assert_eq!(
+                contract.create_proposal(String::from("Tercera"), params(100)),
+                Err(Error::ProposalLimitReached)
+            );
+            assert_eq!(contract.remaining_slots(), 0);
+
+            // Sin límite configurado la capacidad es máxima
+            assert_eq!(VotingSystem::new().remaining_slots(), u32::MAX);
+        }
+
+        #[ink::test]
+        fn test_creacion_por_lotes() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Previa"), params(100)).unwrap();
+
+            // Los IDs continuan la secuencia existente
+            let titulos = Vec::from([String::from("L1"), String::from("L2"), String::from("L3")]);
+            assert_eq!(contract.create_proposals(titulos, params(100)), Ok(Vec::from([1, 2, 3])));
+            assert_eq!(contract.total_proposals(), 4);
+            assert_eq!(contract.get_proposal(3).unwrap().title, "L3");
+
+            // Lotes vacios o demasiado grandes se rechazan
+            assert_eq!(contract.create_proposals(Vec::new(), params(100)), Err(Error::EmptyProposalSet));
+            let enorme = (0..=MAX_BATCH_SIZE).map(|_| String::from("X")).collect();
+            assert_eq!(contract.create_proposals(enorme, params(100)), Err(Error::BatchTooLarge));
+            assert_eq!(contract.total_proposals(), 4);
+
+            // Solo el owner puede crear lotes
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.create_proposals(Vec::from([String::from("L4")]), params(100)),
+                Err(Error::OnlyOwnerCanPerformAction)
+            );
+        }
+
+        #[ink::test]
+        fn test_registro_votos_exitoso() {
+            // Crear contrato y propuesta
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            // Owner crea propuesta
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Propuestarda1"), params(100)).unwrap();
+
+            // Distintos usuarios votan
+            set_caller(accounts.bob);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+            
+            set_caller(accounts.charlie);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+            // Verificar conteo de votos
+            let ProposalView { yes_votes: votos, .. } = contract.get_proposal(0).unwrap();
+            assert_eq!(votos, 2);
+        }
+
+        #[ink::test]
+        fn test_votos_por_opcion() {
+            // Crear contrato y propuesta
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Opciones"), params(100)).unwrap();
+
+            // Cada usuario elige una opcion distinta
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::No).unwrap();
+            set_caller(accounts.django);
+            contract.vote(0, VoteChoice::Abstain).unwrap();
+            set_caller(accounts.eve);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+
+            // Verificar contadores separados
+            let ProposalView {
+                yes_votes: si,
+                no_votes: no,
+                abstain_votes: abstenciones,
+                ..
+            } = contract.get_proposal(0).unwrap();
+            assert_eq!((si, no, abstenciones), (2, 1, 1));
+            // El helper de compatibilidad retorna solo los votos a favor
+            assert_eq!(contract.get_votes(0), Ok(2));
+        }
+
+        #[ink::test]
+        fn test_votos_negativos_y_puntaje_neto() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Señal"), params(100)).unwrap();
+
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            // Votar a favor y en contra comparten la regla de un voto por cuenta
+            assert_eq!(contract.downvote(0), Err(Error::AlreadyVoted));
+            set_caller(accounts.charlie);
+            contract.downvote(0).unwrap();
+            set_caller(accounts.django);
+            contract.downvote(0).unwrap();
+
+            assert_eq!(contract.net_score(0), Ok(-1));
+            assert_eq!(contract.get_proposal(0).unwrap().downvotes, 2);
+            assert_eq!(contract.net_score(99), Err(Error::ProposalDoesNotExist));
+            // El voto negativo emite su propio evento
+            let last = test::recorded_events().last().unwrap();
+            let event = <DownvoteCast as ink::scale::Decode>::decode(&mut &last.data[..]).unwrap();
+            assert_eq!((event.proposal_id, event.voter), (0, accounts.django));
+        }
+
+        #[ink::test]
+        fn test_voto_firmado_rechazos() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Firmada"), params(100)).unwrap();
+
+            // Un relayer no puede usar un nonce viejo ni una firma inválida
+            set_caller(accounts.eve);
+            assert_eq!(contract.get_nonce(accounts.bob), 0);
+            assert_eq!(
+                contract.vote_with_sig(0, accounts.bob, 1, VoteChoice::Yes, [0u8; 64]),
+                Err(Error::BadNonce)
+            );
+            assert_eq!(
+                contract.vote_with_sig(0, accounts.bob, 0, VoteChoice::Yes, [0u8; 64]),
+                Err(Error::InvalidSignature)
+            );
+            assert!(!contract.has_voted(0, accounts.bob));
+            assert_eq!(contract.get_nonce(accounts.bob), 0);
+        }
+
+        #[ink::test]
+        fn test_voto_firmado_por_relayer() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Firmada"), params(100)).unwrap();
+
+            // El votante firma fuera de la cadena y el relayer envía el voto
+            let keypair = schnorrkel::MiniSecretKey::from_bytes(&[7u8; 32])
+                .unwrap()
+                .expand_to_keypair(schnorrkel::ExpansionMode::Ed25519);
+            let voter = AccountId::from(keypair.public.to_bytes());
+            let contrato = ink::env::account_id::<ink::env::DefaultEnvironment>();
+            let sign = |contract_address: AccountId| {
+                let message = (VOTE_SIG_CONTEXT, contract_address, 0u32, voter, 0u64, VoteChoice::Yes).encode();
+                keypair.sign_simple(b"substrate", &message).to_bytes()
+            };
+            set_caller(accounts.eve);
+
+            // Una firma hecha para otro contrato no se acepta en este
+            assert_eq!(
+                contract.vote_with_sig(0, voter, 0, VoteChoice::Yes, sign(accounts.frank)),
+                Err(Error::InvalidSignature)
+            );
+            let signature = sign(contrato);
+            assert!(contract.vote_with_sig(0, voter, 0, VoteChoice::Yes, signature).is_ok());
+            assert_eq!(contract.get_vote(0, voter), Ok(Some(VoteChoice::Yes)));
+            assert_eq!(contract.get_nonce(voter), 1);
+
+            // La misma firma no puede reutilizarse
+            assert_eq!(
+                contract.vote_with_sig(0, voter, 0, VoteChoice::Yes, signature),
+                Err(Error::BadNonce)
+            );
+        }
+
+        #[ink::test]
+        fn test_nonce_por_cuenta() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Nonce"), params(100)).unwrap();
+
+            // Cada acción de la cuenta incrementa su nonce
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            contract.change_vote(0, VoteChoice::No).unwrap();
+            contract.revoke_vote(0).unwrap();
+            contract.delegate(accounts.charlie, u32::MAX).unwrap();
+            contract.undelegate().unwrap();
+            assert_eq!(contract.nonce_of(accounts.bob), 5);
+            assert_eq!(contract.nonce_of(accounts.charlie), 0);
+
+            // Una firma hecha con un nonce anterior ya no sirve
+            assert_eq!(
+                contract.vote_with_sig(0, accounts.bob, 4, VoteChoice::Yes, [0u8; 64]),
+                Err(Error::BadNonce)
+            );
+        }
+
+        #[ink::test]
+        fn test_reputacion_minima() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let confiable = ProposalParams {
+                min_reputation: 5,
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Solo confiables"), confiable).unwrap();
+            contract.create_proposal(String::from("Abierta"), params(100)).unwrap();
+            assert_eq!(contract.set_reputation(accounts.bob, 5), Ok(()));
+            contract.set_reputation(accounts.charlie, 4).unwrap();
+            assert_eq!(contract.reputation_of(accounts.django), 0);
+
+            // Solo el owner asigna reputación
+            set_caller(accounts.bob);
+            assert_eq!(contract.set_reputation(accounts.bob, 100), Err(Error::OnlyOwnerCanPerformAction));
+            assert_eq!(contract.reputation_of(accounts.bob), 5);
+
+            // Por debajo del mínimo se rechaza; sin mínimo cualquiera vota
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::InsufficientReputation));
+            contract.vote(1, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.get_votes(0), Ok(1));
+        }
+
+        #[ink::test]
+        fn test_voto_cuadratico() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Cuadrática"), params(100)).unwrap();
+            assert_eq!(contract.grant_credits(accounts.bob, 10), Ok(()));
+
+            // Un peso de 4 cuesta 16 créditos, uno de 3 cuesta 9
+            set_caller(accounts.bob);
+            assert_eq!(contract.grant_credits(accounts.bob, 10), Err(Error::OnlyOwnerCanPerformAction));
+            assert_eq!(contract.vote_with_weight(0, 4), Err(Error::InsufficientCredits));
+            assert!(contract.vote_with_weight(0, 3).is_ok());
+            assert_eq!(contract.credits_of(accounts.bob), 1);
+            assert_eq!(contract.get_votes(0), Ok(3));
+
+            // Al retirar el voto se devuelven los créditos
+            contract.revoke_vote(0).unwrap();
+            assert_eq!(contract.credits_of(accounts.bob), 10);
+            assert_eq!(contract.get_votes(0), Ok(0));
+
+            // Un peso de 0 no cuenta y se rechaza
+            assert_eq!(contract.vote_with_weight(0, 0), Err(Error::ZeroWeight));
+        }
+
+        #[ink::test]
+        fn test_voto_cuadratico_cobra_el_peso_aplicado() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract
+                .create_proposal(String::from("Con tope"), ProposalParams { max_weight_per_voter: 2, ..params(100) })
+                .unwrap();
+            contract.create_proposal(String::from("Delegada"), params(100)).unwrap();
+            contract.grant_credits(accounts.bob, 20).unwrap();
+
+            // Con tope de 2 votos, pedir 3 suma y cobra solo 2
+            set_caller(accounts.bob);
+            contract.vote_with_weight(0, 3).unwrap();
+            assert_eq!(contract.get_votes(0), Ok(2));
+            assert_eq!(contract.credits_of(accounts.bob), 16);
+
+            // El peso delegado no se suma al voto cuadrático
+            set_caller(accounts.charlie);
+            contract.delegate(accounts.bob, 100).unwrap();
+            set_caller(accounts.bob);
+            contract.vote_with_weight(1, 2).unwrap();
+            assert_eq!(contract.get_votes(1), Ok(2));
+            assert_eq!(contract.credits_of(accounts.bob), 12);
+        }
+
+        #[ink::test]
+        fn test_voto_cuadratico_con_decaimiento() {
+            // Se cobra por los votos que quedan tras el decaimiento, redondeando hacia arriba
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                decay_mode: true,
+                ..Default::default()
+            });
+            contract.create_proposal(String::from("Decae"), params(100)).unwrap();
+            contract.grant_credits(accounts.bob, 4).unwrap();
+            test::set_block_number::<ink::env::DefaultEnvironment>(50);
+            set_caller(accounts.bob);
+            contract.vote_with_weight(0, 2).unwrap();
+            assert_eq!(contract.get_votes(0), Ok(100));
+            assert_eq!(contract.credits_of(accounts.bob), 3);
+        }
+
+        #[ink::test]
+        fn test_peso_asignado_por_el_owner() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Accionistas"), params(100)).unwrap();
+            assert_eq!(contract.set_weight(accounts.bob, 10), Ok(()));
+            contract.set_weight(accounts.django, 0).unwrap();
+            assert_eq!(contract.weight_of(accounts.charlie), 1);
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.set_weight(accounts.bob, 100), Err(Error::OnlyOwnerCanPerformAction));
+
+            // Cada cuenta suma su peso asignado, o 1 por defecto
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::No).unwrap();
+            let proposal = contract.get_proposal(0).unwrap();
+            assert_eq!((proposal.yes_votes, proposal.no_votes), (10, 1));
+
+            // Con peso 0 el voto se rechaza
+            set_caller(accounts.django);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::ZeroWeight));
+        }
+
+        #[ink::test]
+        fn test_voto_ponderado_por_valor() {
+            let mut contract = VotingSystem::new_value_weighted();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Ponderada"), params(100)).unwrap();
+
+            // Cada voto suma el valor transferido
+            set_caller(accounts.bob);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(500);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            set_caller(accounts.charlie);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(200);
+            contract.vote(0, VoteChoice::No).unwrap();
+
+            let ProposalView { yes_votes: si, no_votes: no, .. } = contract.get_proposal(0).unwrap();
+            assert_eq!((si, no), (500, 200));
+            assert_eq!(contract.get_vote_weight(0, accounts.bob), 500);
+            assert_eq!(contract.get_contribution(0, accounts.bob), 500);
+            assert_eq!(contract.get_vote_weight(0, accounts.django), 0);
+
+            // Se mantiene un solo voto por cuenta
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::AlreadyVoted));
+
+            // Un valor mayor que u32::MAX satura el contador
+            set_caller(accounts.django);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(Balance::MAX);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.get_votes(0), Ok(u32::MAX));
+        }
+
+        #[ink::test]
+        fn test_reembolso_de_votos_en_propuesta_cancelada() {
+            let mut contract = VotingSystem::new_value_weighted();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contrato = test::callee::<ink::env::DefaultEnvironment>();
+            test::set_account_balance::<ink::env::DefaultEnvironment>(contrato, 1_000_500);
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("A cancelar"), params(100)).unwrap();
+            set_caller(accounts.bob);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(500);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            assert_eq!(contract.claim_refund(0), Err(Error::NotCancelled));
+
+            // Solo el owner cancela una propuesta con votos
+            assert_eq!(contract.force_cancel(0), Err(Error::OnlyOwnerCanPerformAction));
+            set_caller(accounts.alice);
+            contract.force_cancel(0).unwrap();
+            assert_eq!(contract.force_cancel(0), Err(Error::ProposalCancelled));
+            assert_eq!(contract.get_status(0), Ok(ProposalStatus::Cancelled));
+
+            set_caller(accounts.bob);
+            let saldo_previo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap();
+            contract.claim_refund(0).unwrap();
+            let saldo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap();
+            assert_eq!(saldo - saldo_previo, 500);
+            assert_eq!(contract.get_contribution(0, accounts.bob), 0);
+            assert_eq!(contract.claim_refund(0), Err(Error::NothingToRefund));
+            set_caller(accounts.charlie);
+            assert_eq!(contract.claim_refund(0), Err(Error::NothingToRefund));
+        }
+
+        #[ink::test]
+        fn test_reembolso_de_votos_en_propuesta_vetada() {
+            let mut contract = VotingSystem::new_value_weighted();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contrato = test::callee::<ink::env::DefaultEnvironment>();
+            test::set_account_balance::<ink::env::DefaultEnvironment>(contrato, 1_000_300);
+            set_caller(accounts.alice);
+            contract
+                .create_proposal(String::from("A vetar"), ProposalParams { threshold: 1, ..params(10) })
+                .unwrap();
+            set_caller(accounts.bob);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(300);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            // Cerrada y aprobada, el aporte sigue retenido hasta el veto
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(contract.claim_refund(0), Err(Error::NotCancelled));
+            set_caller(accounts.alice);
+            contract.veto(0).unwrap();
+
+            set_caller(accounts.bob);
+            let saldo_previo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap();
+            contract.claim_refund(0).unwrap();
+            let saldo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap();
+            assert_eq!(saldo - saldo_previo, 300);
+            assert_eq!(contract.claim_refund(0), Err(Error::NothingToRefund));
+        }
+
+        #[ink::test]
+        fn test_cierre_automatico_por_quorum() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let auto_close = ProposalParams {
+                quorum: 2,
+                auto_close_on_quorum: true,
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Cierre por quórum"), auto_close).unwrap();
+
+            // El primer voto no alcanza el quórum
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.get_status(0), Ok(ProposalStatus::Active));
+
+            // El voto que alcanza el quórum cierra la votación
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::No).unwrap();
+            assert_eq!(contract.get_status(0), Ok(ProposalStatus::Closed));
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let last = events.last().unwrap();
+            let closed = <ProposalClosed as ink::scale::Decode>::decode(&mut &last.data[..]).unwrap();
+            assert_eq!(closed.id, 0);
+            assert_eq!(closed.total_votes, 2);
+
+            set_caller(accounts.django);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::ProposalClosed));
+            assert_eq!(contract.get_proposal(0).unwrap().yes_votes, 1);
+        }
+
+        #[ink::test]
+        fn test_tope_de_peso_por_votante() {
+            let mut contract = VotingSystem::new_value_weighted();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let capped = ProposalParams {
+                max_weight_per_voter: 100,
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Con tope"), capped).unwrap();
+
+            // El peso de la ballena se recorta al tope; el resto cuenta completo
+            set_caller(accounts.bob);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(5_000);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            let last = test::recorded_events().last().unwrap();
+            let event = <VoteCast as ink::scale::Decode>::decode(&mut &last.data[..]).unwrap();
+            assert_eq!(event.weight, 100);
+            set_caller(accounts.charlie);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(60);
+            contract.vote(0, VoteChoice::No).unwrap();
+
+            let ProposalView { yes_votes: si, no_votes: no, .. } = contract.get_proposal(0).unwrap();
+            assert_eq!((si, no), (100, 60));
+            // El aporte se conserva completo para el reembolso
+            assert_eq!(contract.get_contribution(0, accounts.bob), 5_000);
+        }
+
+        #[ink::test]
+        fn test_quorum_de_propuesta() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let con_quorum = ProposalParams {
+                quorum: 2,
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Con quorum"), con_quorum).unwrap();
+            contract.create_proposal(String::from("Sin quorum"), params(100)).unwrap();
+
+            // El quorum forma parte de los datos publicos
+            assert_eq!(contract.get_proposal(0).unwrap().quorum, 2);
+            // Un quorum de 0 siempre se considera alcanzado
+            assert_eq!(contract.is_quorum_reached(1), Ok(true));
+
+            // Las abstenciones cuentan para el quorum
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Abstain).unwrap();
+            assert_eq!(contract.is_quorum_reached(0), Ok(false));
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::No).unwrap();
+            assert_eq!(contract.is_quorum_reached(0), Ok(true));
+            assert_eq!(contract.is_quorum_reached(99), Err(Error::ProposalDoesNotExist));
+        }
+
+        #[ink::test]
+        fn test_resultado_completo() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let reglas = ProposalParams {
+                quorum: 3,
+                threshold: 2,
+                ..params(10)
+            };
+            contract.create_proposal(String::from("Resultado"), reglas).unwrap();
+
+            // Dos votos a favor alcanzan el umbral pero no el quórum
+            for voter in [accounts.bob, accounts.charlie] {
+                set_caller(voter);
+                contract.vote(0, VoteChoice::Yes).unwrap();
+            }
+            let result = contract.full_result(0).unwrap();
+            assert_eq!((result.votes, result.total_votes, result.quorum, result.threshold), (2, 2, 3, 2));
+            assert!(!result.quorum_met);
+            assert!(!result.passed);
+            assert_eq!(result.status, ProposalStatus::Active);
+
+            // Con el tercer voto se aprueba, y al vencer el plazo figura cerrada
+            set_caller(accounts.django);
+            contract.vote(0, VoteChoice::No).unwrap();
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            let result = contract.full_result(0).unwrap();
+            assert!(result.quorum_met);
+            assert!(result.passed);
+            assert_eq!(result.status, ProposalStatus::Closed);
+            assert_eq!(contract.full_result(99), Err(Error::ProposalDoesNotExist));
+        }
+
+        #[ink::test]
+        fn test_ganador_entre_propuestas() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            for titulo in ["Candidato 0", "Candidato 1", "Candidato 2"] {
+                contract.create_proposal(String::from(titulo), params(100)).unwrap();
+            }
+
+            // El candidato 2 recibe dos votos y el 1 uno
+            set_caller(accounts.bob);
+            contract.vote(2, VoteChoice::Yes).unwrap();
+            contract.vote(1, VoteChoice::Yes).unwrap();
+            set_caller(accounts.charlie);
+            contract.vote(2, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.winner(Vec::from([0, 1, 2])), Ok(2));
+
+            // Empate: gana el ID mas bajo sin importar el orden
+            set_caller(accounts.django);
+            contract.vote(1, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.winner(Vec::from([2, 1])), Ok(1));
+
+            // Conjuntos invalidos
+            assert_eq!(contract.winner(Vec::from([0, 99])), Err(Error::ProposalDoesNotExist));
+            assert_eq!(contract.winner(Vec::new()), Err(Error::EmptyProposalSet));
+        }
+
+        #[ink::test]
+        fn test_desempate_pseudoaleatorio() {
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                tie_break_random: true,
+                ..Default::default()
+            });
+            for titulo in ["Candidato 0", "Candidato 1", "Candidato 2", "Candidato 3"] {
+                contract.create_proposal(String::from(titulo), params(100)).unwrap();
+            }
+
+            // Con un bloque fijo el resultado es estable, no depende del orden de los IDs y no
+            // favorece necesariamente al más bajo
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            let elegido = contract.winner(Vec::from([0, 1, 2, 3])).unwrap();
+            assert_eq!(elegido, 2);
+            assert_eq!(contract.winner(Vec::from([3, 2, 1, 0, 2])), Ok(elegido));
+
+            // Solo se sortea entre los empatados
+            set_caller(accounts.bob);
+            contract.vote(1, VoteChoice::Yes).unwrap();
+            contract.vote(3, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.winner(Vec::from([0, 1, 2, 3])), Ok(1));
+
+            // En la segunda vuelta instantánea se sortea la opción eliminada entre las empatadas
+            set_caller(accounts.alice);
+            let candidates = ProposalParams {
+                options: vec![String::from("A"), String::from("B"), String::from("C")],
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Elección"), candidates).unwrap();
+            for (voter, ranking) in [
+                (accounts.bob, vec![0]),
+                (accounts.charlie, vec![0]),
+                (accounts.django, vec![1]),
+                (accounts.eve, vec![2, 1]),
+            ] {
+                set_caller(voter);
+                contract.vote_ranked(4, ranking).unwrap();
+            }
+            assert_eq!(contract.tally_irv(4), Ok(0));
+        }
+
+        #[ink::test]
+        fn test_busqueda_por_texto() {
+            let mut contract = VotingSystem::new();
+            set_caller(test::default_accounts::<ink::env::DefaultEnvironment>().alice);
+            contract.create_proposal(String::from("Presupuesto 2026"), params(100)).unwrap();
+            contract
+                .create_proposal_with_description(String::from("Obras"), String::from("Parte del presupuesto"), params(100))
+                .unwrap();
+            contract.create_proposal(String::from("Elecciones"), params(100)).unwrap();
+
+            // Coincide en el título o en la descripción, distinguiendo mayúsculas
+            assert_eq!(contract.search_proposals(String::from("resupuesto")), vec![0, 1]);
+            assert_eq!(contract.search_proposals(String::from("Presupuesto")), vec![0]);
+            assert!(contract.search_proposals(String::from("Inexistente")).is_empty());
+
+            // Textos vacíos o demasiado largos no devuelven resultados
+            assert!(contract.search_proposals(String::new()).is_empty());
+            let largo = "o".repeat(MAX_SEARCH_LEN as usize + 1);
+            assert!(contract.search_proposals(largo).is_empty());
+        }
+
+        #[ink::test]
+        fn test_etiquetas_y_filtro() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let etiquetada = |tags: &[&str]| ProposalParams {
+                tags: tags.iter().map(|tag| String::from(*tag)).collect(),
+                ..params(100)
+            };
+            contract.create_proposal(String::from("E0"), etiquetada(&["obras", "barrio"])).unwrap();
+            contract.create_proposal(String::from("E1"), etiquetada(&["salud"])).unwrap();
+            contract.create_proposal(String::from("E2"), etiquetada(&["obras"])).unwrap();
+
+            // Consulta y filtro por etiqueta
+            assert_eq!(contract.get_tags(1), Ok(Vec::from([String::from("salud")])));
+            assert_eq!(contract.get_tags(99), Err(Error::ProposalDoesNotExist));
+            assert_eq!(contract.proposals_by_tag(String::from("obras")), Vec::from([0, 2]));
+            assert!(contract.proposals_by_tag(String::from("otra")).is_empty());
+
+            // Limite de etiquetas por propuesta
+            let demasiadas = etiquetada(&["a", "b", "c", "d", "e", "f"]);
+            assert_eq!(contract.create_proposal(String::from("E3"), demasiadas), Err(Error::TooManyTags));
+        }
+
+        #[ink::test]
+        fn test_creador_de_propuestas() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("De Alice"), params(100)).unwrap();
+
+            // Tras transferir la propiedad, Bob crea la siguiente
+            contract.transfer_ownership(accounts.bob).unwrap();
+            set_caller(accounts.bob);
+            contract.accept_ownership().unwrap();
+            contract.create_proposal(String::from("De Bob"), params(100)).unwrap();
+
+            assert_eq!(contract.get_creator(0), Ok(accounts.alice));
+            assert_eq!(contract.get_creator(1), Ok(accounts.bob));
+            assert_eq!(contract.get_creator(99), Err(Error::ProposalDoesNotExist));
+            assert_eq!(contract.proposals_by_creator(accounts.bob), Vec::from([1]));
+            assert!(contract.proposals_by_creator(accounts.charlie).is_empty());
+        }
+
+        #[ink::test]
+        fn test_fecha_de_creacion() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            contract.create_proposal(String::from("Temprana"), params(100)).unwrap();
+            test::set_block_timestamp::<ink::env::DefaultEnvironment>(2_000);
+            contract.create_proposal(String::from("Tardia"), params(100)).unwrap();
+
+            // La marca de tiempo queda registrada en la vista
+            assert_eq!(contract.get_proposal(0).unwrap().created_at, 1_000);
+            assert_eq!(contract.get_proposal(1).unwrap().created_at, 2_000);
+
+            // Filtro estricto por fecha de creacion
+            assert_eq!(contract.proposals_created_after(999), Vec::from([0, 1]));
+            assert_eq!(contract.proposals_created_after(1_000), Vec::from([1]));
+            assert!(contract.proposals_created_after(2_000).is_empty());
+        }
+
+        #[ink::test]
+        fn test_ranking_de_propuestas() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            for title in ["A", "B", "C", "D"] {
+                contract.create_proposal(String::from(title), params(100)).unwrap();
+            }
+            for voter in [accounts.bob, accounts.charlie] {
+                set_caller(voter);
+                contract.vote(2, VoteChoice::Yes).unwrap();
+            }
+            set_caller(accounts.bob);
+            contract.vote(1, VoteChoice::Yes).unwrap();
+            contract.vote(3, VoteChoice::Yes).unwrap();
+
+            // Orden descendente, empate resuelto por el ID menor y propuestas eliminadas omitidas
+            set_caller(accounts.alice);
+            contract.delete_proposal(0).unwrap();
+            assert_eq!(contract.top_proposals(10), vec![(2, 2), (1, 1), (3, 1)]);
+            assert_eq!(contract.top_proposals(2), vec![(2, 2), (1, 1)]);
+            assert!(contract.top_proposals(0).is_empty());
+        }
+
+        #[ink::test]
+        fn test_listado_paginado() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            for titulo in ["P0", "P1", "P2", "P3"] {
+                contract.create_proposal(String::from(titulo), params(100)).unwrap();
+            }
+            contract.delete_proposal(1).unwrap();
+
+            // Las propuestas eliminadas se omiten
+            let pagina = contract.list_proposals(0, 3);
+            let ids: Vec<u32> = pagina.iter().map(|(id, _, _)| *id).collect();
+            assert_eq!(ids, Vec::from([0, 2]));
+            assert_eq!(pagina[1].1, "P2");
+
+            // Paginas fuera de rango quedan vacias o recortadas
+            assert_eq!(contract.list_proposals(3, 10).len(), 1);
+            assert!(contract.list_proposals(10, 10).is_empty());
+            assert!(contract.list_proposals(u32::MAX, u32::MAX).is_empty());
+        }
+
+        #[ink::test]
+        fn test_delegacion_de_voto() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Delegada"), params(100)).unwrap();
+
+            // Bob y Charlie delegan en Django
+            set_caller(accounts.bob);
+            assert_eq!(contract.delegate(accounts.bob, u32::MAX), Err(Error::CannotDelegateToSelf));
+            contract.delegate(accounts.django, u32::MAX).unwrap();
+            set_caller(accounts.charlie);
+            contract.delegate(accounts.django, u32::MAX).unwrap();
+            assert_eq!(contract.get_delegate(accounts.bob), Some((accounts.django, u32::MAX)));
+
+            // Django no puede delegar en quien ya le delego
+            set_caller(accounts.django);
+            assert_eq!(contract.delegate(accounts.bob, u32::MAX), Err(Error::DelegationCycle));
+
+            // Los delegantes no votan directamente y su peso fluye al delegado
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote(0, VoteChoice::No), Err(Error::VoteDelegated));
+            set_caller(accounts.django);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.get_votes(0), Ok(3));
+            assert_eq!(contract.get_vote_weight(0, accounts.django), 3);
+
+            // Al retirar la delegacion se recupera el voto propio
+            set_caller(accounts.eve);
+            assert_eq!(contract.undelegate(), Err(Error::NotDelegated));
+            set_caller(accounts.charlie);
+            contract.undelegate().unwrap();
+            assert_eq!(contract.get_delegate(accounts.charlie), None);
+        }
+
+        #[ink::test]
+        fn test_delegacion_en_cadena() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Cadena"), params(100)).unwrap();
+
+            // Bob delega en Charlie y Charlie en Django: Django vota con los tres
+            set_caller(accounts.bob);
+            contract.delegate(accounts.charlie, u32::MAX).unwrap();
+            set_caller(accounts.charlie);
+            contract.delegate(accounts.django, u32::MAX).unwrap();
+            assert_eq!(contract.resolve_weight(accounts.django), 3);
+            assert_eq!(contract.resolve_weight(accounts.charlie), 2);
+            set_caller(accounts.django);
+            assert_eq!(contract.delegate(accounts.bob, u32::MAX), Err(Error::DelegationCycle));
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.get_votes(0), Ok(3));
+        }
+
+        #[ink::test]
+        fn test_delegante_no_vota_dos_veces() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Doble"), params(100)).unwrap();
+
+            // Bob delega en Charlie, que vota con el peso de ambos
+            set_caller(accounts.bob);
+            contract.delegate(accounts.charlie, u32::MAX).unwrap();
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.get_votes(0), Ok(2));
+
+            // Al retirar la delegación Bob no puede votar de nuevo en esa propuesta
+            set_caller(accounts.bob);
+            contract.undelegate().unwrap();
+            assert_eq!(contract.vote(0, VoteChoice::No), Err(Error::AlreadyVoted));
+
+            // Tampoco suma en el voto de otro delegado
+            contract.delegate(accounts.django, u32::MAX).unwrap();
+            set_caller(accounts.django);
+            contract.vote(0, VoteChoice::No).unwrap();
+            assert_eq!(contract.get_vote_weight(0, accounts.django), 1);
+
+            // Si Charlie retira su voto, Bob queda liberado y su peso vuelve a sumar
+            set_caller(accounts.bob);
+            contract.undelegate().unwrap();
+            set_caller(accounts.charlie);
+            contract.revoke_vote(0).unwrap();
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::No).unwrap();
+            assert_eq!(contract.get_votes(0), Ok(0));
+            assert_eq!(contract.full_result(0).unwrap().total_votes, 2);
+        }
+
+        #[ink::test]
+        fn test_profundidad_maxima_de_delegacion() {
+            let mut contract = VotingSystem::new();
+            let chain: Vec<AccountId> = (1..=MAX_DELEGATION_DEPTH + 2)
+                .map(|index| AccountId::from([index as u8; 32]))
+                .collect();
+
+            // Una cadena de MAX_DELEGATION_DEPTH saltos es válida; uno más se rechaza
+            for pair in chain.windows(2).take(MAX_DELEGATION_DEPTH as usize) {
+                set_caller(pair[0]);
+                contract.delegate(pair[1], u32::MAX).unwrap();
+            }
+            let last = chain[MAX_DELEGATION_DEPTH as usize];
+            assert_eq!(contract.resolve_weight(last), MAX_DELEGATION_DEPTH + 1);
+            set_caller(last);
+            assert_eq!(
+                contract.delegate(chain[MAX_DELEGATION_DEPTH as usize + 1], u32::MAX),
+                Err(Error::DelegationTooDeep)
+            );
+            // Tampoco se puede alargar la cadena desde el inicio
+            set_caller(AccountId::from([0xff; 32]));
+            assert_eq!(contract.delegate(chain[0], u32::MAX), Err(Error::DelegationTooDeep));
+        }
+
+        #[ink::test]
+        fn test_vencimiento_de_la_delegacion() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Vigente"), params(100)).unwrap();
+            contract.create_proposal(String::from("Vencida"), params(100)).unwrap();
+
+            // La delegación de Bob aplica hasta el bloque 2 inclusive
+            set_caller(accounts.bob);
+            assert_eq!(contract.delegate(accounts.charlie, 2), Ok(()));
+            test::set_block_number::<ink::env::DefaultEnvironment>(2);
+            assert_eq!(contract.get_delegate(accounts.bob), Some((accounts.charlie, 2)));
+            assert_eq!(contract.resolve_weight(accounts.charlie), 2);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::VoteDelegated));
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.get_votes(0), Ok(2));
+
+            // En el bloque 3 venció: no suma peso y Bob vota sin retirarla
+            test::set_block_number::<ink::env::DefaultEnvironment>(3);
+            assert_eq!(contract.get_delegate(accounts.bob), None);
+            assert_eq!(contract.resolve_weight(accounts.charlie), 1);
+            contract.vote(1, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.get_votes(1), Ok(1));
+            set_caller(accounts.bob);
+            assert_eq!(contract.undelegate(), Err(Error::NotDelegated));
+            contract.vote(1, VoteChoice::No).unwrap();
+
+            // En la propuesta en la que Charlie ya votó con su peso, Bob no vota otra vez
+            assert_eq!(contract.vote(0, VoteChoice::No), Err(Error::AlreadyVoted));
+            assert_eq!(contract.get_votes(0), Ok(2));
+
+            // No se puede delegar con un vencimiento pasado
+            assert_eq!(contract.delegate(accounts.charlie, 2), Err(Error::InvalidExpiry));
+        }
+
+        #[ink::test]
+        fn test_voto_secreto_compromiso_y_revelacion() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let secreta = ProposalParams {
+                commit_reveal: true,
+                ..params(10)
+            };
+            contract.create_proposal(String::from("Secreta"), secreta).unwrap();
+            contract.create_proposal(String::from("Publica"), params(10)).unwrap();
+
+            let salt = [7u8; 32];
+            let compromiso = |choice: VoteChoice| {
+                let mut hash = [0u8; 32];
+                ink::env::hash_bytes::<Blake2x256>(&(choice, salt).encode(), &mut hash);
+                hash
+            };
+
+            // Durante el plazo solo se aceptan compromisos
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::WrongVotingMode));
+            assert_eq!(contract.commit_vote(1, compromiso(VoteChoice::Yes)), Err(Error::WrongVotingMode));
+            contract.commit_vote(0, compromiso(VoteChoice::Yes)).unwrap();
+            assert_eq!(contract.commit_vote(0, compromiso(VoteChoice::No)), Err(Error::AlreadyCommitted));
+            assert_eq!(contract.reveal_vote(0, VoteChoice::Yes, salt), Err(Error::RevealNotStarted));
+
+            // Vencido el plazo se revela y se cuenta el voto
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(contract.reveal_vote(0, VoteChoice::No, salt), Err(Error::CommitmentMismatch));
+            assert!(contract.reveal_vote(0, VoteChoice::Yes, salt).is_ok());
+            assert_eq!(contract.get_votes(0), Ok(1));
+            assert_eq!(contract.reveal_vote(0, VoteChoice::Yes, salt), Err(Error::HasNotVoted));
+
+            // Ya no se aceptan compromisos nuevos
+            set_caller(accounts.charlie);
+            assert_eq!(contract.commit_vote(0, compromiso(VoteChoice::No)), Err(Error::VotingClosed));
+        }
+
+        #[ink::test]
+        fn test_fase_de_revelacion() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract
+                .create_proposal(String::from("Plazo"), ProposalParams { commit_reveal: true, ..params(10) })
+                .unwrap();
+            contract
+                .create_proposal(String::from("Cerrada"), ProposalParams { commit_reveal: true, ..params(20) })
+                .unwrap();
+            let salt = [9u8; 32];
+            let mut compromiso = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(&(VoteChoice::Yes, salt).encode(), &mut compromiso);
+            for account in [accounts.bob, accounts.django] {
+                set_caller(account);
+                contract.commit_vote(0, compromiso).unwrap();
+            }
+            set_caller(accounts.charlie);
+            contract.commit_vote(1, compromiso).unwrap();
+
+            // Cerrar antes del plazo adelanta la revelación en lugar de impedirla
+            test::set_block_number::<ink::env::DefaultEnvironment>(5);
+            set_caller(accounts.alice);
+            contract.close_proposal(1).unwrap();
+            set_caller(accounts.charlie);
+            contract.reveal_vote(1, VoteChoice::Yes, salt).unwrap();
+            assert_eq!(contract.finalize(1), Err(Error::VotingStillOpen));
+
+            // Al vencer el plazo nadie puede finalizar mientras dure la revelación
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(contract.get_status(0), Ok(ProposalStatus::Active));
+            assert_eq!(contract.finalize(0), Err(Error::VotingStillOpen));
+            set_caller(accounts.bob);
+            contract.reveal_vote(0, VoteChoice::Yes, salt).unwrap();
+
+            // Terminada la revelación se finaliza y ya no se aceptan revelaciones
+            test::set_block_number::<ink::env::DefaultEnvironment>(10 + DEFAULT_REVEAL_PERIOD);
+            assert_eq!(contract.get_status(1), Ok(ProposalStatus::Closed));
+            contract.finalize(1).unwrap();
+            set_caller(accounts.django);
+            assert_eq!(contract.reveal_vote(0, VoteChoice::Yes, salt), Err(Error::RevealEnded));
+            contract.finalize(0).unwrap();
+            assert_eq!(contract.get_votes(0), Ok(1));
+            assert_eq!(contract.get_votes(1), Ok(1));
+        }
+
+        #[ink::test]
+        fn test_umbral_de_aprobacion() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let con_umbral = ProposalParams {
+                threshold: 2,
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Con umbral"), con_umbral).unwrap();
+            contract.create_proposal(String::from("Sin umbral"), params(100)).unwrap();
+            assert_eq!(contract.get_proposal(0).unwrap().threshold, 2);
+            assert_eq!(contract.has_passed(1), Ok(true));
+
+            // Solo cuentan los votos a favor
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::No).unwrap();
+            assert_eq!(contract.has_passed(0), Ok(false));
+            set_caller(accounts.django);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.has_passed(0), Ok(true));
+            assert_eq!(contract.has_passed(99), Err(Error::ProposalDoesNotExist));
+        }
+
+        #[ink::test]
+        fn test_mayoria_calificada() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let two_thirds = ProposalParams {
+                pass_numerator: 2,
+                pass_denominator: 3,
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Dos tercios"), two_thirds).unwrap();
+            contract.create_proposal(String::from("Simple"), params(100)).unwrap();
+            let invalid = ProposalParams {
+                pass_numerator: 3,
+                pass_denominator: 2,
+                ..params(100)
+            };
+            assert_eq!(
+                contract.create_proposal(String::from("Inválida"), invalid),
+                Err(Error::InvalidThreshold)
+            );
+
+            // Con dos a favor y uno en contra se alcanzan los dos tercios y la mayoría simple
+            for (voter, choice) in [
+                (accounts.bob, VoteChoice::Yes),
+                (accounts.charlie, VoteChoice::No),
+                (accounts.django, VoteChoice::Yes),
+            ] {
+                set_caller(voter);
+                contract.vote(0, choice).unwrap();
+                contract.vote(1, choice).unwrap();
+            }
+            assert_eq!(contract.has_passed(0), Ok(true));
+
+            // Un segundo voto en contra deja la primera por debajo de los dos tercios
+            set_caller(accounts.eve);
+            contract.vote(0, VoteChoice::No).unwrap();
+            contract.vote(1, VoteChoice::No).unwrap();
+            assert_eq!(contract.has_passed(0), Ok(false));
+            assert_eq!(contract.has_passed(1), Ok(true));
+        }
+
+        #[ink::test]
+        fn test_propuesta_de_opcion_multiple() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let multiple = ProposalParams {
+                options: Vec::from([String::from("Rojo"), String::from("Verde"), String::from("Azul")]),
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Color"), multiple).unwrap();
+            let unica = ProposalParams {
+                options: Vec::from([String::from("Apoyo")]),
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Contador"), unica).unwrap();
+
+            // Se vota por indice de opcion
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::WrongVotingMode));
+            assert_eq!(contract.vote(0, VoteChoice::Option(3)), Err(Error::InvalidOption));
+            contract.vote(0, VoteChoice::Option(2)).unwrap();
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::Option(2)).unwrap();
+            contract.change_vote(0, VoteChoice::Option(0)).unwrap();
+
+            let vista = contract.get_proposal(0).unwrap();
+            assert_eq!(vista.options.len(), 3);
+            assert_eq!(vista.option_votes, Vec::from([1, 0, 1]));
+
+            // Con una sola opcion funciona como contador unico
+            contract.vote(1, VoteChoice::Option(0)).unwrap();
+            assert_eq!(contract.get_proposal(1).unwrap().option_votes, Vec::from([1]));
+            // Las propuestas de si/no no aceptan indices
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Binaria"), params(100)).unwrap();
+            assert_eq!(contract.vote(2, VoteChoice::Option(0)), Err(Error::WrongVotingMode));
+
+            // Resultados por opción en una sola consulta
+            let resultados = contract.get_option_results(0).unwrap();
+            assert_eq!(resultados[0], (String::from("Rojo"), 1));
+            assert_eq!(resultados.iter().map(|(_, votos)| *votos).collect::<Vec<_>>(), vec![1, 0, 1]);
+            assert_eq!(contract.get_option_results(2), Ok(Vec::new()));
+            assert_eq!(contract.get_option_results(99), Err(Error::ProposalDoesNotExist));
+        }
+
+        #[ink::test]
+        fn test_condiciones_de_ejecucion() {
+            // El entorno off-chain no soporta llamadas entre contratos; se verifican las condiciones
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let executable = ProposalParams {
+                threshold: 1,
+                action: Some(ProposalAction {
+                    target: accounts.frank,
+                    selector: [0xde, 0xad, 0xbe, 0xef],
+                    input: Vec::new(),
+                }),
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Ejecutable"), executable.clone()).unwrap();
+            contract.create_proposal(String::from("Sin acción"), params(100)).unwrap();
+            assert_eq!(contract.execute(0), Err(Error::VotingStillOpen));
+
+            // Sin votos a favor no se aprueba; sin acción no hay nada que ejecutar
+            contract.close_proposal(0).unwrap();
+            contract.close_proposal(1).unwrap();
+            assert_eq!(contract.execute(0), Err(Error::ProposalNotPassed));
+            assert_eq!(contract.execute(1), Err(Error::NotExecutable));
+            assert_eq!(contract.execute(99), Err(Error::ProposalDoesNotExist));
+
+            // Una llamada anidada desde el destino encuentra el cerrojo tomado
+            contract.locked.set(&true);
+            assert_eq!(contract.execute(0), Err(Error::Reentrancy));
+        }
+
+        #[ink::test]
+        fn test_finalizacion_de_resultados() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let approvable = ProposalParams {
+                threshold: 1,
+                ..params(10)
+            };
+            contract.create_proposal(String::from("Final"), approvable).unwrap();
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.finalize(0), Err(Error::VotingStillOpen));
+            assert_eq!(contract.get_result(0), Err(Error::NotFinalized));
+
+            // Vencido el plazo se congela el resultado una sola vez
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            contract.finalize(0).unwrap();
+            assert_eq!(contract.get_result(0), Ok((1, true)));
+            assert_eq!(contract.get_status(0), Ok(ProposalStatus::Finalized));
+            assert_eq!(contract.finalize(0), Err(Error::AlreadyFinalized));
+
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let last = events.last().unwrap();
+            let event = <ProposalFinalized as ink::scale::Decode>::decode(&mut &last.data[..]).unwrap();
+            assert_eq!((event.id, event.final_votes, event.passed), (0, 1, true));
+
+            // Una propuesta finalizada no acepta más votos
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote(0, VoteChoice::No), Err(Error::AlreadyFinalized));
+        }
+
+        #[ink::test]
+        fn test_veto_del_owner() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let approvable = ProposalParams {
+                threshold: 1,
+                ..params(10)
+            };
+            contract.create_proposal(String::from("Vetable"), approvable.clone()).unwrap();
+            contract.create_proposal(String::from("Finalizada"), approvable).unwrap();
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            contract.vote(1, VoteChoice::Yes).unwrap();
+            set_caller(accounts.alice);
+            assert_eq!(contract.veto(0), Err(Error::VotingStillOpen));
+
+            // Cerrada la votación solo el owner veta, y nunca una propuesta ya finalizada
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            contract.finalize(1).unwrap();
+            assert_eq!(contract.veto(1), Err(Error::AlreadyFinalized));
+            set_caller(accounts.bob);
+            assert_eq!(contract.veto(0), Err(Error::OnlyOwnerCanPerformAction));
+            set_caller(accounts.alice);
+            assert_eq!(contract.has_passed(0), Ok(true));
+            contract.veto(0).unwrap();
+            assert_eq!(contract.is_vetoed(0), Ok(true));
+            assert_eq!(contract.has_passed(0), Ok(false));
+            assert_eq!(contract.execute(0), Err(Error::ProposalNotPassed));
+            contract.finalize(0).unwrap();
+            assert_eq!(contract.get_result(0), Ok((1, false)));
+        }
+
+        #[ink::test]
+        fn test_dependencia_entre_propuestas() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let approvable = ProposalParams {
+                threshold: 1,
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Etapa 1"), approvable.clone()).unwrap();
+            let staged = ProposalParams {
+                depends_on: Some(0),
+                ..approvable.clone()
+            };
+            contract.create_proposal(String::from("Etapa 2"), staged).unwrap();
+            let orphan = ProposalParams {
+                depends_on: Some(99),
+                ..params(100)
+            };
+            assert_eq!(
+                contract.create_proposal(String::from("Huérfana"), orphan),
+                Err(Error::ProposalDoesNotExist)
+            );
+            assert_eq!(contract.get_dependency(1), Ok(Some(0)));
+            assert_eq!(contract.get_dependency(0), Ok(None));
+
+            // La segunda etapa no se ejecuta hasta que la primera se finalice aprobada
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            contract.vote(1, VoteChoice::Yes).unwrap();
+            set_caller(accounts.alice);
+            contract.close_proposal(0).unwrap();
+            contract.close_proposal(1).unwrap();
+            assert_eq!(contract.execute(1), Err(Error::DependencyNotMet));
+            contract.finalize(0).unwrap();
+            assert_eq!(contract.execute(1), Err(Error::NotExecutable));
+        }
+
+        #[ink::test]
+        fn test_inicio_diferido_de_votacion() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            let delayed = ProposalParams {
+                start_block: 20,
+                ..params(30)
+            };
+            contract.create_proposal(String::from("Diferida"), delayed).unwrap();
+            assert_eq!(contract.get_start_block(0), Ok(20));
+
+            // El inicio debe ser anterior al cierre; un inicio pasado abre de inmediato
+            let too_late = ProposalParams {
+                start_block: 40,
+                ..params(30)
+            };
+            assert_eq!(
+                contract.create_proposal(String::from("Tardía"), too_late),
+                Err(Error::InvalidStartBlock)
+            );
+            let past = ProposalParams {
+                start_block: 5,
+                ..params(30)
+            };
+            contract.create_proposal(String::from("Inmediata"), past).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::VotingNotStarted));
+            assert!(contract.vote(1, VoteChoice::Yes).is_ok());
+            test::set_block_number::<ink::env::DefaultEnvironment>(20);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+        }
+
+        #[ink::test]
+        fn test_gestion_de_propuesta_antes_del_inicio() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            let delayed = ProposalParams {
+                start_block: 20,
+                ..params(30)
+            };
+            contract.create_proposal(String::from("Corregible"), delayed.clone()).unwrap();
+            contract.create_proposal(String::from("Cancelable"), delayed).unwrap();
+
+            // Antes del inicio se puede corregir o cancelar, aunque todavía no se pueda votar
+            assert!(contract.update_thresholds(0, 2, 1).is_ok());
+            assert_eq!(contract.get_proposal(0).map(|p| (p.quorum, p.threshold)), Ok((2, 1)));
+            assert!(contract.cancel_proposal(1).is_ok());
+            assert_eq!(contract.get_status(1), Ok(ProposalStatus::Cancelled));
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::VotingNotStarted));
+        }
+
+        #[ink::test]
+        fn test_voto_acumulativo() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let cumulative = ProposalParams {
+                options: vec![String::from("A"), String::from("B"), String::from("C")],
+                point_budget: 10,
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Consejo"), cumulative).unwrap();
+            assert_eq!(contract.get_proposal(0).unwrap().point_budget, 10);
+
+            // Papeletas inválidas
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote_cumulative(0, vec![(0, 6), (1, 5)]), Err(Error::BudgetExceeded));
+            assert_eq!(contract.vote_cumulative(0, vec![(3, 1)]), Err(Error::InvalidOption));
+            assert_eq!(contract.vote_cumulative(0, vec![(1, 1), (1, 2)]), Err(Error::DuplicateOption));
+
+            // Los puntos se reparten libremente y la papeleta es única
+            contract.vote_cumulative(0, vec![(2, 7), (0, 3)]).unwrap();
+            assert_eq!(contract.vote_cumulative(0, vec![(1, 1)]), Err(Error::AlreadyVoted));
+            set_caller(accounts.charlie);
+            contract.vote_cumulative(0, vec![(2, 10)]).unwrap();
+            assert_eq!(contract.get_proposal(0).unwrap().option_votes, vec![3, 0, 17]);
+
+            // Al retirar la papeleta se descuentan todos sus puntos
+            set_caller(accounts.bob);
+            contract.revoke_vote(0).unwrap();
+            assert_eq!(contract.get_proposal(0).unwrap().option_votes, vec![0, 0, 10]);
+        }
+
+        #[ink::test]
+        fn test_voto_por_aprobacion() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let committee = ProposalParams {
+                options: vec![String::from("A"), String::from("B"), String::from("C")],
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Comité"), committee).unwrap();
+
+            // Papeletas inválidas
+            set_caller(accounts.bob);
+            assert_eq!(contract.approve(0, vec![0, 2, 0]), Err(Error::DuplicateOption));
+            assert_eq!(contract.approve(0, vec![0, 3]), Err(Error::InvalidOption));
+
+            // Cada opción aprobada suma una vez y la papeleta es única
+            contract.approve(0, vec![0, 2]).unwrap();
+            assert_eq!(contract.approve(0, vec![1]), Err(Error::AlreadyVoted));
+            set_caller(accounts.charlie);
+            contract.approve(0, vec![2]).unwrap();
+            assert_eq!(contract.get_proposal(0).unwrap().option_votes, vec![1, 0, 2]);
+            // La participación cuenta una vez por papeleta
+            assert_eq!(contract.full_result(0).map(|result| result.total_votes), Ok(2));
+
+            // Al retirar el voto se descuentan todas las opciones aprobadas
+            set_caller(accounts.bob);
+            assert_eq!(contract.change_vote(0, VoteChoice::Option(1)), Err(Error::WrongVotingMode));
+            contract.revoke_vote(0).unwrap();
+            assert_eq!(contract.get_proposal(0).unwrap().option_votes, vec![0, 0, 1]);
+            assert_eq!(contract.full_result(0).map(|result| result.total_votes), Ok(1));
+        }
+
+        #[ink::test]
+        fn test_segunda_vuelta_instantanea() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let candidates = ProposalParams {
+                options: vec![String::from("A"), String::from("B"), String::from("C")],
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Elección"), candidates).unwrap();
+            contract.create_proposal(String::from("Sí o no"), params(100)).unwrap();
+            assert_eq!(contract.tally_irv(0), Err(Error::NoVotes));
+
+            // Rankings inválidos
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote_ranked(0, vec![]), Err(Error::InvalidOption));
+            assert_eq!(contract.vote_ranked(0, vec![0, 0]), Err(Error::InvalidOption));
+            assert_eq!(contract.vote_ranked(0, vec![0, 3]), Err(Error::InvalidOption));
+            assert_eq!(contract.vote_ranked(1, vec![0]), Err(Error::WrongVotingMode));
+
+            // Primeras preferencias: A 2, B 2, C 1. Se elimina C y su voto pasa a B
+            contract.vote_ranked(0, vec![0, 1]).unwrap();
+            set_caller(accounts.charlie);
+            contract.vote_ranked(0, vec![0]).unwrap();
+            set_caller(accounts.django);
+            contract.vote_ranked(0, vec![1, 0]).unwrap();
+            set_caller(accounts.eve);
+            contract.vote(0, VoteChoice::Option(1)).unwrap();
+            set_caller(accounts.frank);
+            contract.vote_ranked(0, vec![2, 1]).unwrap();
+            assert_eq!(contract.get_proposal(0).unwrap().option_votes, vec![2, 2, 1]);
+            assert_eq!(contract.tally_irv(0), Ok(1));
+
+            // Si el votante de C no tiene segunda preferencia, A y B empatan y se elimina B
+            contract.revoke_vote(0).unwrap();
+            contract.vote(0, VoteChoice::Option(2)).unwrap();
+            assert_eq!(contract.tally_irv(0), Ok(0));
+            assert_eq!(contract.tally_irv(1), Err(Error::WrongVotingMode));
+            assert_eq!(contract.tally_irv(99), Err(Error::ProposalDoesNotExist));
+        }
+
+        #[ink::test]
+        fn test_conteo_de_borda() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let candidates = ProposalParams {
+                options: vec![String::from("A"), String::from("B"), String::from("C")],
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Elección"), candidates).unwrap();
+            contract.create_proposal(String::from("Sí o no"), params(100)).unwrap();
+            assert_eq!(contract.tally_borda(0), Ok(vec![(0, 0), (1, 0), (2, 0)]));
+
+            // A-B-C: 2, 1, 0. C-B: 0, 1, 2. B parcial: 0, 2, 0. Voto simple por C: 0, 0, 2
+            set_caller(accounts.bob);
+            contract.vote_ranked(0, vec![0, 1, 2]).unwrap();
+            set_caller(accounts.charlie);
+            contract.vote_ranked(0, vec![2, 1]).unwrap();
+            set_caller(accounts.django);
+            contract.vote_ranked(0, vec![1]).unwrap();
+            set_caller(accounts.eve);
+            contract.vote(0, VoteChoice::Option(2)).unwrap();
+            assert_eq!(contract.tally_borda(0), Ok(vec![(1, 4), (2, 4), (0, 2)]));
+
+            assert_eq!(contract.tally_borda(1), Err(Error::WrongVotingMode));
+            assert_eq!(contract.tally_borda(99), Err(Error::ProposalDoesNotExist));
+        }
+
+        #[ink::test]
+        fn test_cierre_por_plazo() {
+            // Crear contrato y propuesta con plazo de 10 bloques
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            test::set_block_number::<ink::env::DefaultEnvironment>(5);
+            contract.create_proposal(String::from("Con plazo"), params(10)).unwrap();
+            assert_eq!(contract.get_deadline(0), Ok(15));
+
+            // Dentro del plazo se puede votar
+            set_caller(accounts.bob);
+            test::set_block_number::<ink::env::DefaultEnvironment>(14);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+
+            // Al llegar al bloque final la votacion se cierra
+            set_caller(accounts.charlie);
+            test::set_block_number::<ink::env::DefaultEnvironment>(15);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::VotingClosed));
+        }
+
+        #[ink::test]
+        fn test_duracion_cero_cierra_inmediatamente() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Cerrada"), params(0)).unwrap();
+
+            // Sin duracion no se acepta ningun voto
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::VotingClosed));
+            assert_eq!(contract.get_deadline(99), Err(Error::ProposalDoesNotExist));
+        }
+
+        #[ink::test]
+        fn test_cierre_manual_de_propuesta() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Cierre manual"), params(100)).unwrap();
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+
+            // Solo el owner puede cerrar
+            assert_eq!(contract.close_proposal(0), Err(Error::Unauthorized));
+            set_caller(accounts.alice);
+            assert_eq!(contract.close_proposal(99), Err(Error::ProposalDoesNotExist));
+            assert!(contract.close_proposal(0).is_ok());
+
+            // Una vez cerrada no se aceptan votos
+            set_caller(accounts.charlie);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::ProposalClosed));
+        }
+
+        #[ink::test]
+        fn test_correccion_de_quorum_y_umbral() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Corregible"), params(100)).unwrap();
+            assert_eq!(contract.update_thresholds(99, 1, 1), Err(Error::ProposalDoesNotExist));
+            set_caller(accounts.bob);
+            assert_eq!(contract.update_thresholds(0, 1, 1), Err(Error::OnlyOwnerCanPerformAction));
+
+            set_caller(accounts.alice);
+            contract.update_thresholds(0, 3, 2).unwrap();
+            let ProposalView { quorum, threshold, .. } = contract.get_proposal(0).unwrap();
+            assert_eq!((quorum, threshold), (3, 2));
+            let last = test::recorded_events().last().unwrap();
+            let event = <ThresholdsUpdated as ink::scale::Decode>::decode(&mut &last.data[..]).unwrap();
+            assert_eq!((event.id, event.quorum, event.threshold), (0, 3, 2));
+
+            // Con un voto emitido ya no se pueden mover
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            set_caller(accounts.alice);
+            assert_eq!(contract.update_thresholds(0, 1, 1), Err(Error::ProposalHasVotes));
+        }
+
+        #[ink::test]
+        fn test_reapertura_de_propuestas() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Cerrada antes"), params(100)).unwrap();
+            contract.create_proposal(String::from("Vencida"), params(10)).unwrap();
+            assert_eq!(contract.reopen_proposal(0, 10), Err(Error::ProposalNotClosed));
+
+            // Un cierre manual se revierte y el plazo se extiende
+            contract.close_proposal(0).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(contract.reopen_proposal(0, 10), Err(Error::OnlyOwnerCanPerformAction));
+            set_caller(accounts.alice);
+            contract.reopen_proposal(0, 10).unwrap();
+            assert_eq!(contract.get_status(0), Ok(ProposalStatus::Active));
+            assert_eq!(contract.get_deadline(0), Ok(110));
+
+            // Una vencida vuelve a abrir desde el bloque actual; una finalizada ya no
+            test::set_block_number::<ink::env::DefaultEnvironment>(20);
+            contract.reopen_proposal(1, 5).unwrap();
+            assert_eq!(contract.get_deadline(1), Ok(25));
+            set_caller(accounts.bob);
+            contract.vote(1, VoteChoice::Yes).unwrap();
+            test::set_block_number::<ink::env::DefaultEnvironment>(25);
+            set_caller(accounts.alice);
+            contract.finalize(1).unwrap();
+            assert_eq!(contract.reopen_proposal(1, 5), Err(Error::AlreadyFinalized));
+        }
+
+        #[ink::test]
+        fn test_extension_de_plazo() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Extendida"), params(10)).unwrap();
+
+            // Solo el owner puede extender una propuesta existente
+            set_caller(accounts.bob);
+            assert_eq!(contract.extend_deadline(0, 5), Err(Error::OnlyOwnerCanPerformAction));
+            set_caller(accounts.alice);
+            assert_eq!(contract.extend_deadline(99, 5), Err(Error::ProposalDoesNotExist));
+            assert!(contract.extend_deadline(0, 5).is_ok());
+            assert_eq!(contract.get_deadline(0), Ok(15));
+
+            // Se puede votar despues del plazo original
+            set_caller(accounts.bob);
+            test::set_block_number::<ink::env::DefaultEnvironment>(12);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+
+            // Una propuesta cerrada no se extiende
+            set_caller(accounts.alice);
+            contract.close_proposal(0).unwrap();
+            assert_eq!(contract.extend_deadline(0, 5), Err(Error::ProposalClosed));
+        }
+
+        #[ink::test]
+        fn test_patrocinio_de_borradores() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                min_sponsors: 2,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Borrador"), params(100)).unwrap();
+            assert_eq!(contract.get_status(0), Ok(ProposalStatus::Draft));
+
+            // Un borrador no acepta votos y cada cuenta patrocina una vez
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::ProposalNotActive));
+            contract.sponsor(0).unwrap();
+            assert_eq!(contract.sponsor(0), Err(Error::AlreadySponsored));
+            assert_eq!(contract.get_status(0), Ok(ProposalStatus::Draft));
+
+            // Con el segundo patrocinio se activa
+            set_caller(accounts.charlie);
+            contract.sponsor(0).unwrap();
+            assert_eq!(contract.get_status(0), Ok(ProposalStatus::Active));
+            assert_eq!(contract.get_proposal(0).unwrap().sponsor_count, 2);
+            set_caller(accounts.django);
+            assert_eq!(contract.sponsor(0), Err(Error::NotDraft));
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+        }
+
+        #[ink::test]
+        fn test_borrador_conserva_el_deposito() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                min_sponsors: 1,
+                proposal_deposit: 100,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            contract.create_proposal(String::from("Borrador"), params(10)).unwrap();
+
+            // El depósito de un borrador no se devuelve, ni siquiera vencido su plazo
+            assert_eq!(contract.refund_deposit(0), Err(Error::ProposalNotClosed));
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(contract.refund_deposit(0), Err(Error::ProposalNotClosed));
+            assert_eq!(contract.proposals.get(0).unwrap().deposit, 100);
+        }
+
+        #[ink::test]
+        fn test_propuestas_abiertas() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                min_sponsors: 1,
+                ..Default::default()
+            });
+            set_caller(test::default_accounts::<ink::env::DefaultEnvironment>().alice);
+            contract.create_proposal(String::from("Borrador"), params(100)).unwrap();
+            for title in ["Corta", "Larga", "Cerrada"] {
+                let duration = if title == "Corta" { 10 } else { 100 };
+                contract.create_proposal(String::from(title), params(duration)).unwrap();
+                contract.sponsor(contract.last_proposal_id().unwrap()).unwrap();
+            }
+            let deferred = ProposalParams {
+                start_block: 20,
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Diferida"), deferred).unwrap();
+            contract.sponsor(4).unwrap();
+            contract.close_proposal(3).unwrap();
+            assert_eq!(contract.open_proposals(), vec![1, 2]);
+
+            // La corta vence y la diferida empieza
+            test::set_block_number::<ink::env::DefaultEnvironment>(20);
+            assert_eq!(contract.open_proposals(), vec![2, 4]);
+        }
+
+        #[ink::test]
+        fn test_cancelacion_por_el_creador() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Errónea"), params(100)).unwrap();
+            contract.create_proposal(String::from("Votada"), params(100)).unwrap();
+            set_caller(accounts.bob);
+            contract.vote(1, VoteChoice::Yes).unwrap();
+
+            // Solo el creador cancela, y solo sin votos
+            assert_eq!(contract.cancel_proposal(0), Err(Error::Unauthorized));
+            set_caller(accounts.alice);
+            assert_eq!(contract.cancel_proposal(1), Err(Error::ProposalHasVotes));
+            assert!(contract.cancel_proposal(0).is_ok());
+            assert_eq!(contract.get_status(0), Ok(ProposalStatus::Cancelled));
+
+            // Una propuesta cancelada no acepta votos
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::ProposalCancelled));
+        }
+
+        #[ink::test]
+        fn test_estado_de_propuesta() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Estado manual"), params(100)).unwrap();
+            contract.create_proposal(String::from("Estado por plazo"), params(10)).unwrap();
+
+            // Las propuestas nacen activas
+            assert_eq!(contract.get_status(0), Ok(ProposalStatus::Active));
+            assert_eq!(contract.get_status(99), Err(Error::ProposalDoesNotExist));
+
+            // Cierre manual
+            contract.close_proposal(0).unwrap();
+            assert_eq!(contract.get_status(0), Ok(ProposalStatus::Closed));
+
+            // Cierre por vencimiento del plazo
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(contract.get_status(1), Ok(ProposalStatus::Closed));
+        }
+
+        #[ink::test]
+        fn test_eliminacion_de_propuesta() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("A eliminar"), params(100)).unwrap();
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+
+            // Solo el owner puede eliminar
+            assert_eq!(contract.delete_proposal(0), Err(Error::Unauthorized));
+            set_caller(accounts.alice);
+            assert_eq!(contract.delete_proposal(99), Err(Error::ProposalDoesNotExist));
+            assert!(contract.delete_proposal(0).is_ok());
+
+            // La propuesta y sus votantes desaparecen del storage
+            assert_eq!(contract.get_proposal(0), Err(Error::ProposalDoesNotExist));
+            assert!(!contract.voters.contains((0, accounts.bob)));
+            assert_eq!(contract.delete_proposal(0), Err(Error::ProposalDoesNotExist));
+        }
+
+        #[ink::test]
+        fn test_eliminacion_devuelve_aportes_y_limpia_registros() {
+            let mut contract = VotingSystem::new_value_weighted();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contrato = test::callee::<ink::env::DefaultEnvironment>();
+            test::set_account_balance::<ink::env::DefaultEnvironment>(contrato, 1_000_500);
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Aportada"), params(100)).unwrap();
+            set_caller(accounts.bob);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(500);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            // El aporte vuelve al votante y deja de estar retenido
+            set_caller(accounts.alice);
+            let saldo_previo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap();
+            contract.delete_proposal(0).unwrap();
+            let saldo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap();
+            assert_eq!(saldo - saldo_previo, 500);
+            assert_eq!(contract.get_contribution(0, accounts.bob), 0);
+            assert_eq!(contract.locked_funds, 0);
+        }
+
+        #[ink::test]
+        fn test_eliminacion_limpia_compromisos_y_patrocinios() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                min_sponsors: 2,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Borrador"), params(100)).unwrap();
+            contract
+                .create_proposal(String::from("Secreta"), ProposalParams { commit_reveal: true, ..params(100) })
+                .unwrap();
+            set_caller(accounts.bob);
+            contract.sponsor(0).unwrap();
+            contract.sponsor(1).unwrap();
+            set_caller(accounts.charlie);
+            contract.sponsor(1).unwrap();
+            set_caller(accounts.bob);
+            contract.commit_vote(1, [7; 32]).unwrap();
+
+            // Los compromisos y patrocinios de una propuesta eliminada no quedan en el storage
+            set_caller(accounts.alice);
+            contract.delete_proposal(0).unwrap();
+            assert!(!contract.sponsors.contains((0, accounts.bob)));
+            contract.delete_proposal(1).unwrap();
+            assert!(!contract.commitments.contains((1, accounts.bob)));
+            assert!(!contract.sponsors.contains((1, accounts.charlie)));
+        }
+
+        #[ink::test]
+        fn test_eliminacion_devuelve_creditos() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Cuadrática"), params(100)).unwrap();
+            contract.grant_credits(accounts.bob, 10).unwrap();
+            set_caller(accounts.bob);
+            contract.vote_with_weight(0, 3).unwrap();
+            assert_eq!(contract.credits_of(accounts.bob), 1);
+
+            // Al eliminar la propuesta se devuelven los créditos gastados, como al retirar el voto
+            set_caller(accounts.alice);
+            contract.delete_proposal(0).unwrap();
+            assert_eq!(contract.credits_of(accounts.bob), 10);
+            assert!(!contract.spent_credits.contains((0, accounts.bob)));
+        }
+
+        #[ink::test]
+        fn test_ultima_propuesta() {
+            let mut contract = VotingSystem::new();
+            set_caller(test::default_accounts::<ink::env::DefaultEnvironment>().alice);
+            assert_eq!(contract.last_proposal_id(), None);
+            contract.create_proposal(String::from("Primera"), params(100)).unwrap();
+            contract.create_proposal(String::from("Segunda"), params(100)).unwrap();
+            assert_eq!(contract.last_proposal_id(), Some(1));
+
+            // Tras eliminar la más reciente se informa la anterior, y ninguna al vaciarse
+            contract.delete_proposal(1).unwrap();
+            assert_eq!(contract.last_proposal_id(), Some(0));
+            assert_eq!(contract.live_proposal_count(), 1);
+            contract.delete_proposal(0).unwrap();
+            assert_eq!(contract.last_proposal_id(), None);
+        }
+
+        #[ink::test]
+        fn test_conteo_por_estado() {
+            let mut contract = VotingSystem::new();
+            set_caller(test::default_accounts::<ink::env::DefaultEnvironment>().alice);
+            for title in ["A", "B", "C", "D", "E"] {
+                contract.create_proposal(String::from(title), params(10)).unwrap();
+            }
+            assert_eq!(contract.count_by_status(), (5, 0, 0, 0));
+
+            // Cierre, cancelación, finalización y eliminación mueven los contadores
+            contract.close_proposal(0).unwrap();
+            contract.close_proposal(1).unwrap();
+            contract.cancel_proposal(2).unwrap();
+            assert_eq!(contract.count_by_status(), (2, 2, 0, 1));
+            contract.finalize(1).unwrap();
+            assert_eq!(contract.count_by_status(), (2, 1, 1, 1));
+            contract.delete_proposal(3).unwrap();
+            contract.delete_proposal(2).unwrap();
+            assert_eq!(contract.count_by_status(), (1, 1, 1, 0));
+
+            // Una propuesta vencida cuenta como activa hasta finalizarla
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(contract.count_by_status(), (1, 1, 1, 0));
+            contract.finalize(4).unwrap();
+            assert_eq!(contract.count_by_status(), (0, 1, 2, 0));
+        }
+
+        #[ink::test]
+        fn test_ids_de_propuestas_existentes() {
+            let mut contract = VotingSystem::new();
+            set_caller(test::default_accounts::<ink::env::DefaultEnvironment>().alice);
+            for title in ["A", "B", "C"] {
+                contract.create_proposal(String::from(title), params(100)).unwrap();
+            }
+            assert_eq!(contract.live_proposal_count(), 3);
+
+            // Las eliminadas no aparecen ni cuentan, aunque el contador de IDs no retrocede
+            contract.delete_proposal(1).unwrap();
+            assert_eq!(contract.active_proposal_ids(), vec![0, 2]);
+            assert_eq!(contract.live_proposal_count(), 2);
+            assert_eq!(contract.total_proposals(), 3);
+        }
+
+        #[ink::test]
+        fn test_pausa_del_contrato() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Antes de pausar"), params(100)).unwrap();
+
+            // Solo el owner puede pausar
+            set_caller(accounts.bob);
+            assert_eq!(contract.pause(), Err(Error::OnlyOwnerCanPerformAction));
+            set_caller(accounts.alice);
+            assert!(contract.pause().is_ok());
+            assert!(contract.is_paused());
+
+            // Las acciones que cambian estado fallan, las consultas no
+            assert_eq!(
+                contract.create_proposal(String::from("Pausada"), params(100)),
+                Err(Error::CreationPaused)
+            );
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::VotingPaused));
+            assert!(contract.get_proposal(0).is_ok());
+            assert_eq!(contract.total_proposals(), 1);
+
+            // Al reanudar se vuelve a votar
+            set_caller(accounts.alice);
+            assert!(contract.unpause().is_ok());
+            set_caller(accounts.bob);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+        }
+
+        #[ink::test]
+        fn test_configuracion_del_contrato() {
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                max_proposals: 10,
+                proposal_deposit: 500,
+                ..Default::default()
+            });
+            let config = contract.get_config();
+            assert_eq!(config.owner, accounts.alice);
+            assert_eq!(config.owners, vec![accounts.alice]);
+            assert_eq!(config.max_proposals, 10);
+            assert_eq!(config.proposal_deposit, 500);
+            assert!(!config.allowlist_enabled);
+            assert!(!config.paused);
+
+            // Refleja los cambios posteriores y la consulta no exige permisos
+            contract.set_allowlist_enabled(true).unwrap();
+            contract.set_creation_paused(true).unwrap();
+            set_caller(accounts.bob);
+            let config = contract.get_config();
+            assert!(config.allowlist_enabled);
+            assert!(config.paused);
+            assert!(config.creation_paused);
+            assert!(!config.voting_paused);
+        }
+
+        #[ink::test]
+        fn test_gobierno_multifirma() {
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                owners: vec![accounts.alice, accounts.bob, accounts.charlie, accounts.bob],
+                required_approvals: 2,
+                ..Default::default()
+            });
+            assert_eq!(contract.get_owners(), vec![accounts.alice, accounts.bob, accounts.charlie]);
+            assert_eq!(contract.required_approvals(), 2);
+
+            // Las acciones privilegiadas directas quedan bloqueadas
+            assert_eq!(contract.pause(), Err(Error::NotEnoughApprovals));
+            assert_eq!(
+                contract.create_proposal(String::from("Directa"), params(100)),
+                Err(Error::NotEnoughApprovals)
+            );
+
+            // Una sola aprobación no alcanza
+            let create = GovernanceAction::CreateProposal {
+                title: String::from("Multifirma"),
+                description: String::new(),
+                params: Box::new(params(100)),
+            };
+            assert_eq!(contract.propose_action(create), Ok(0));
+            assert_eq!(contract.execute_action(0), Err(Error::NotEnoughApprovals));
+            assert_eq!(contract.approve_action(0), Err(Error::AlreadyApproved));
+            set_caller(accounts.django);
+            assert_eq!(contract.approve_action(0), Err(Error::OnlyOwnerCanPerformAction));
+            assert_eq!(contract.propose_action(GovernanceAction::Pause), Err(Error::OnlyOwnerCanPerformAction));
+
+            // Con la segunda aprobación se ejecuta una única vez
+            set_caller(accounts.bob);
+            assert!(contract.approve_action(0).is_ok());
+            assert_eq!(contract.get_action(0).map(|a| a.approvals), Ok(2));
+            assert!(contract.execute_action(0).is_ok());
+            assert_eq!(contract.last_proposal_id(), Some(0));
+            assert_eq!(contract.get_creator(0), Ok(accounts.bob));
+            assert_eq!(contract.execute_action(0), Err(Error::AlreadyExecuted));
+            assert_eq!(contract.approve_action(1), Err(Error::ActionDoesNotExist));
+
+            // Cierre y pausa también pasan por aprobaciones
+            set_caller(accounts.alice);
+            assert_eq!(contract.close_proposal(0), Err(Error::NotEnoughApprovals));
+            assert_eq!(contract.propose_action(GovernanceAction::CloseProposal(0)), Ok(1));
+            assert_eq!(contract.propose_action(GovernanceAction::Pause), Ok(2));
+            set_caller(accounts.charlie);
+            assert!(contract.approve_action(1).is_ok());
+            assert!(contract.approve_action(2).is_ok());
+            assert!(contract.execute_action(1).is_ok());
+            assert!(contract.execute_action(2).is_ok());
+            assert_eq!(contract.get_status(0), Ok(ProposalStatus::Closed));
+            assert!(contract.is_paused());
+        }
+
+        #[ink::test]
+        fn test_acciones_privilegiadas_en_modo_multifirma() {
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            set_caller(accounts.alice);
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                owners: vec![accounts.alice, accounts.bob],
+                required_approvals: 2,
+                ..Default::default()
+            });
+            let run = |contract: &mut VotingSystem, action: GovernanceAction| {
+                set_caller(accounts.alice);
+                let id = contract.propose_action(action).unwrap();
+                set_caller(accounts.bob);
+                contract.approve_action(id).unwrap();
+                contract.execute_action(id)
+            };
+            let create = GovernanceAction::CreateProposal {
+                title: String::from("Multifirma"),
+                description: String::new(),
+                params: Box::new(params(100)),
+            };
+            run(&mut contract, create).unwrap();
+
+            // Ningún mensaje privilegiado se ejecuta con una sola firma
+            set_caller(accounts.alice);
+            let blocked = Err(Error::NotEnoughApprovals);
+            assert_eq!(
+                contract.create_proposals(vec![String::from("Lote")], params(100)),
+                Err(Error::NotEnoughApprovals)
+            );
+            assert_eq!(contract.close_proposal(0), blocked);
+            assert_eq!(contract.force_cancel(0), blocked);
+            assert_eq!(contract.update_thresholds(0, 1, 1), blocked);
+            assert_eq!(contract.extend_deadline(0, 10), blocked);
+            assert_eq!(contract.reopen_proposal(0, 10), blocked);
+            assert_eq!(contract.veto(0), blocked);
+            assert_eq!(contract.delete_proposal(0), blocked);
+            assert_eq!(contract.pause(), blocked);
+            assert_eq!(contract.unpause(), blocked);
+            assert_eq!(contract.set_voting_paused(true), blocked);
+            assert_eq!(contract.set_creation_paused(true), blocked);
+            assert_eq!(contract.withdraw_treasury(1, accounts.frank), blocked);
+            assert_eq!(contract.set_code_hash(Hash::from([1u8; 32])), blocked);
+            assert_eq!(contract.snapshot(), blocked);
+            assert_eq!(contract.blacklist(accounts.eve), blocked);
+            assert_eq!(contract.unblacklist(accounts.eve), blocked);
+            assert_eq!(contract.add_moderator(accounts.eve), blocked);
+            assert_eq!(contract.remove_moderator(accounts.eve), blocked);
+            assert_eq!(contract.add_voter(accounts.eve), blocked);
+            assert_eq!(contract.remove_voter(accounts.eve), blocked);
+            assert_eq!(contract.open_registration(0, 10), blocked);
+            assert_eq!(contract.set_eligibility_root(0, None), blocked);
+            assert_eq!(contract.set_allowlist_enabled(true), blocked);
+            assert_eq!(contract.grant_credits(accounts.eve, 1), blocked);
+            assert_eq!(contract.set_reputation(accounts.eve, 1), blocked);
+            assert_eq!(contract.set_weight(accounts.eve, 1), blocked);
+            assert_eq!(contract.transfer_ownership(accounts.eve), blocked);
+            assert_eq!(contract.renounce_ownership(), blocked);
+
+            // Cada uno tiene su acción de gobierno, que se ejecuta al reunir las aprobaciones
+            let lote = GovernanceAction::CreateProposals {
+                titles: vec![String::from("Lote A"), String::from("Lote B")],
+                params: Box::new(params(10)),
+            };
+            assert_eq!(run(&mut contract, lote), Ok(()));
+            assert_eq!(contract.last_proposal_id(), Some(2));
+            let umbrales = GovernanceAction::UpdateThresholds { proposal_id: 0, quorum: 1, threshold: 1 };
+            assert_eq!(run(&mut contract, umbrales), Ok(()));
+            let propuesta = contract.get_proposal(0).unwrap();
+            assert_eq!((propuesta.quorum, propuesta.threshold), (1, 1));
+            let extension = GovernanceAction::ExtendDeadline { proposal_id: 0, additional_blocks: 10 };
+            assert_eq!(run(&mut contract, extension), Ok(()));
+            assert_eq!(contract.get_deadline(0), Ok(110));
+
+            set_caller(accounts.charlie);
+            contract.vote(1, VoteChoice::Yes).unwrap();
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(run(&mut contract, GovernanceAction::Veto(1)), Ok(()));
+            assert_eq!(contract.is_vetoed(1), Ok(true));
+            let reapertura = GovernanceAction::ReopenProposal { proposal_id: 2, additional_blocks: 5 };
+            assert_eq!(run(&mut contract, reapertura), Ok(()));
+            assert_eq!(contract.get_status(2), Ok(ProposalStatus::Active));
+            assert_eq!(run(&mut contract, GovernanceAction::ForceCancel(2)), Ok(()));
+            assert_eq!(contract.get_status(2), Ok(ProposalStatus::Cancelled));
+            assert_eq!(run(&mut contract, GovernanceAction::Snapshot), Ok(()));
+            assert_eq!(contract.get_snapshot_count(), 1);
+            let raiz = GovernanceAction::SetEligibilityRoot { proposal_id: 0, root: Some([1u8; 32]) };
+            assert_eq!(run(&mut contract, raiz), Ok(()));
+            assert_eq!(contract.proposals.get(0).unwrap().eligibility_root, Some([1u8; 32]));
+
+            assert_eq!(run(&mut contract, GovernanceAction::Blacklist(accounts.frank)), Ok(()));
+            assert!(contract.is_blacklisted(accounts.frank));
+            assert_eq!(run(&mut contract, GovernanceAction::Unblacklist(accounts.frank)), Ok(()));
+            assert!(!contract.is_blacklisted(accounts.frank));
+            assert_eq!(run(&mut contract, GovernanceAction::AddModerator(accounts.eve)), Ok(()));
+            assert!(contract.is_moderator(accounts.eve));
+            assert_eq!(run(&mut contract, GovernanceAction::RemoveModerator(accounts.eve)), Ok(()));
+            assert!(!contract.is_moderator(accounts.eve));
+
+            // Con la lista de votantes activa, solo vota quien se habilita por aprobaciones
+            assert_eq!(run(&mut contract, GovernanceAction::SetAllowlistEnabled(true)), Ok(()));
+            assert_eq!(run(&mut contract, GovernanceAction::AddVoter(accounts.eve)), Ok(()));
+            assert_eq!(run(&mut contract, GovernanceAction::AddVoter(accounts.frank)), Ok(()));
+            assert_eq!(run(&mut contract, GovernanceAction::RemoveVoter(accounts.frank)), Ok(()));
+            assert!(contract.is_eligible(accounts.eve));
+            assert!(!contract.is_eligible(accounts.frank));
+            let registro = GovernanceAction::OpenRegistration { from: 20, to: 30 };
+            assert_eq!(run(&mut contract, registro), Ok(()));
+            let config = contract.get_config();
+            assert_eq!((config.registration_open_block, config.registration_close_block), (20, 30));
+
+            let creditos = GovernanceAction::GrantCredits { account: accounts.eve, amount: 9 };
+            assert_eq!(run(&mut contract, creditos), Ok(()));
+            assert_eq!(contract.credits_of(accounts.eve), 9);
+            let reputacion = GovernanceAction::SetReputation { account: accounts.eve, value: 3 };
+            assert_eq!(run(&mut contract, reputacion), Ok(()));
+            assert_eq!(contract.reputation_of(accounts.eve), 3);
+            let peso = GovernanceAction::SetWeight { account: accounts.eve, weight: 2 };
+            assert_eq!(run(&mut contract, peso), Ok(()));
+            assert_eq!(contract.weight_of(accounts.eve), 2);
+
+            // La propiedad se transfiere o se renuncia también por aprobaciones
+            assert_eq!(run(&mut contract, GovernanceAction::TransferOwnership(accounts.charlie)), Ok(()));
+            set_caller(accounts.charlie);
+            contract.accept_ownership().unwrap();
+            assert_eq!(contract.get_owner(), accounts.charlie);
+            assert_eq!(run(&mut contract, GovernanceAction::RenounceOwnership), Ok(()));
+            assert_eq!(contract.get_owner(), AccountId::from([0u8; 32]));
+
+            // Las pausas, la eliminación y los retiros pasan por aprobaciones
+            assert_eq!(run(&mut contract, GovernanceAction::SetVotingPaused(true)), Ok(()));
+            assert_eq!(run(&mut contract, GovernanceAction::SetCreationPaused(true)), Ok(()));
+            assert!(contract.is_paused());
+            assert_eq!(run(&mut contract, GovernanceAction::DeleteProposal(0)), Ok(()));
+            assert_eq!(contract.get_proposal(0), Err(Error::ProposalDoesNotExist));
+            test::set_account_balance::<ink::env::DefaultEnvironment>(accounts.django, 1_000_000);
+            let saldo_previo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.frank).unwrap();
+            let withdraw = GovernanceAction::WithdrawTreasury { amount: 50, to: accounts.frank };
+            assert_eq!(run(&mut contract, withdraw), Ok(()));
+            let saldo = test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.frank).unwrap();
+            assert_eq!(saldo, saldo_previo + 50);
+
+            // El entorno off-chain no soporta `set_code_hash`; se verifica que pueda proponerse
+            set_caller(accounts.alice);
+            assert!(contract.propose_action(GovernanceAction::SetCodeHash(Hash::from([1u8; 32]))).is_ok());
+        }
+
+        #[ink::test]
+        fn test_pausas_independientes() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("En curso"), params(100)).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(contract.set_creation_paused(true), Err(Error::OnlyOwnerCanPerformAction));
+
+            // Con la creación pausada los votos en curso siguen
+            set_caller(accounts.alice);
+            contract.set_creation_paused(true).unwrap();
+            assert!(contract.is_paused());
+            assert_eq!(
+                contract.create_proposal(String::from("Nueva"), params(100)),
+                Err(Error::CreationPaused)
+            );
+            set_caller(accounts.bob);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+
+            // Con los votos pausados se puede crear pero no votar
+            set_caller(accounts.alice);
+            contract.set_creation_paused(false).unwrap();
+            contract.set_voting_paused(true).unwrap();
+            assert!(contract.create_proposal(String::from("Nueva"), params(100)).is_ok());
+            set_caller(accounts.charlie);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::VotingPaused));
+        }
+
+        #[ink::test]
+        fn test_proteccion_contra_reentrada() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Protegida"), params(100)).unwrap();
+
+            // Simular una llamada anidada mientras el cerrojo está tomado
+            contract.locked.set(&true);
+            assert_eq!(
+                contract.create_proposal(String::from("Anidada"), params(100)),
+                Err(Error::Reentrancy)
+            );
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::Reentrancy));
+            assert_eq!(contract.change_vote(0, VoteChoice::No), Err(Error::Reentrancy));
+            assert_eq!(contract.vote_with_weight(0, 1), Err(Error::Reentrancy));
+            assert_eq!(contract.approve(0, vec![0]), Err(Error::Reentrancy));
+            assert_eq!(contract.vote_cumulative(0, vec![(0, 1)]), Err(Error::Reentrancy));
+            assert_eq!(contract.commit_vote(0, [0; 32]), Err(Error::Reentrancy));
+            assert_eq!(contract.reveal_vote(0, VoteChoice::Yes, [0; 32]), Err(Error::Reentrancy));
+            assert_eq!(
+                contract.vote_with_sig(0, accounts.bob, 0, VoteChoice::Yes, [0; 64]),
+                Err(Error::Reentrancy)
+            );
+            // Las consultas no se ven afectadas
+            assert_eq!(contract.total_proposals(), 1);
+
+            // Al liberarse, los mensajes vuelven a funcionar y liberan el cerrojo al salir
+            contract.locked.set(&false);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+            assert_eq!(contract.locked.get(), Some(false));
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::AlreadyVoted));
+            assert_eq!(contract.locked.get(), Some(false));
+        }
+
+        #[ink::test]
+        fn test_sin_token_de_gobernanza_no_se_exige_saldo() {
+            // Sin token configurado el saldo mínimo se ignora
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                min_token_balance: 1_000,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Sin token"), params(100)).unwrap();
+            set_caller(accounts.bob);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+        }
+
+        #[ink::test]
+        fn test_saldo_del_token_en_la_instantanea() {
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                governance_token: Some(accounts.frank),
+                min_token_balance: 1_000,
+                ..Default::default()
+            });
+            test::set_block_number::<ink::env::DefaultEnvironment>(5);
+            contract.create_proposal(String::from("Instantánea"), params(100)).unwrap();
+            test::set_block_number::<ink::env::DefaultEnvironment>(8);
+            assert_eq!(contract.get_snapshot_block(0), Ok(5));
+            assert_eq!(contract.get_snapshot_block(99), Err(Error::ProposalDoesNotExist));
+
+            // Los saldos ya resueltos se reutilizan sin volver a consultar el token
+            contract.token_balances.insert((0, accounts.bob), &2_000);
+            contract.token_balances.insert((0, accounts.charlie), &999);
+            set_caller(accounts.bob);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+            set_caller(accounts.charlie);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::InsufficientTokenBalance));
+        }
+
+        #[ink::test]
+        fn test_lista_de_cuentas_bloqueadas() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                allowlist_enabled: true,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Moderada"), params(100)).unwrap();
+            contract.blacklist(accounts.bob).unwrap();
+            assert!(contract.is_blacklisted(accounts.bob));
+
+            // El bloqueo se verifica antes que la habilitación
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::AccountBlacklisted));
+            assert_eq!(contract.blacklist(accounts.charlie), Err(Error::OnlyOwnerCanPerformAction));
+
+            // Un owner bloqueado tampoco crea propuestas
+            set_caller(accounts.alice);
+            contract.blacklist(accounts.alice).unwrap();
+            assert_eq!(
+                contract.create_proposal(String::from("Bloqueada"), params(100)),
+                Err(Error::AccountBlacklisted)
+            );
+
+            // Al desbloquear vuelven a aplicarse las reglas habituales
+            contract.unblacklist(accounts.bob).unwrap();
+            contract.add_voter(accounts.bob).unwrap();
+            set_caller(accounts.bob);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+        }
+
+        #[ink::test]
+        fn test_participacion_del_padron() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                allowlist_enabled: true,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let binding = ProposalParams {
+                min_turnout: 50,
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Vinculante"), binding).unwrap();
+            assert_eq!(contract.turnout(0), Err(Error::NoRegisteredVoters));
+
+            // Cuatro registrados (uno repetido no cuenta) y uno votó: 25%
+            for voter in [accounts.bob, accounts.charlie, accounts.django, accounts.eve, accounts.eve] {
+                contract.add_voter(voter).unwrap();
+            }
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.turnout(0), Ok(25));
+            assert_eq!(contract.turnout_met(0), Ok(false));
+
+            // Con dos votos se alcanza el mínimo
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::No).unwrap();
+            assert_eq!(contract.turnout(0), Ok(50));
+            assert_eq!(contract.turnout_met(0), Ok(true));
+            assert_eq!(contract.turnout(99), Err(Error::ProposalDoesNotExist));
+        }
+
+        #[ink::test]
+        fn test_registro_de_votantes() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                allowlist_enabled: true,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.bob);
+            assert_eq!(contract.register(), Err(Error::RegistrationClosed));
+            assert_eq!(contract.open_registration(5, 10), Err(Error::OnlyOwnerCanPerformAction));
+            set_caller(accounts.alice);
+            contract.open_registration(5, 10).unwrap();
+            contract.create_proposal(String::from("Abierta"), params(100)).unwrap();
+
+            // Solo dentro del período y una vez por cuenta
+            set_caller(accounts.bob);
+            test::set_block_number::<ink::env::DefaultEnvironment>(4);
+            assert_eq!(contract.register(), Err(Error::RegistrationClosed));
+            test::set_block_number::<ink::env::DefaultEnvironment>(5);
+            assert!(contract.register().is_ok());
+            assert_eq!(contract.register(), Err(Error::AlreadyRegistered));
+            assert!(contract.is_registered(accounts.bob));
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            set_caller(accounts.charlie);
+            assert_eq!(contract.register(), Err(Error::RegistrationClosed));
+
+            // Los registrados votan y cuentan en el padrón
+            set_caller(accounts.bob);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+            assert_eq!(contract.turnout(0), Ok(100));
+        }
+
+        fn merkle_hash(input: &[u8]) -> [u8; 32] {
+            let mut output = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(input, &mut output);
+            output
+        }
+
+        fn merkle_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+            let (first, second) = if a <= b { (a, b) } else { (b, a) };
+            merkle_hash(&[first, second].concat())
+        }
+
+        #[ink::test]
+        fn test_elegibilidad_por_prueba_de_merkle() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Padrón grande"), params(100)).unwrap();
+
+            // Árbol de cuatro hojas: bob, charlie, django y eve
+            let leaves = [accounts.bob, accounts.charlie, accounts.django, accounts.eve]
+                .map(|account| merkle_hash(account.as_ref()));
+            let left = merkle_pair(leaves[0], leaves[1]);
+            let right = merkle_pair(leaves[2], leaves[3]);
+            let root = merkle_pair(left, right);
+            set_caller(accounts.bob);
+            assert_eq!(contract.set_eligibility_root(0, Some(root)), Err(Error::OnlyOwnerCanPerformAction));
+            set_caller(accounts.alice);
+            contract.set_eligibility_root(0, Some(root)).unwrap();
+
+            // Sin prueba, con una prueba ajena o fuera del árbol no se puede votar
+            set_caller(accounts.bob);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::InvalidProof));
+            assert_eq!(
+                contract.vote_with_proof(0, VoteChoice::Yes, vec![leaves[0], right]),
+                Err(Error::InvalidProof)
+            );
+            contract.vote_with_proof(0, VoteChoice::Yes, vec![leaves[1], right]).unwrap();
+            set_caller(accounts.eve);
+            contract.vote_with_proof(0, VoteChoice::No, vec![leaves[2], left]).unwrap();
+            set_caller(accounts.frank);
+            assert_eq!(
+                contract.vote_with_proof(0, VoteChoice::No, vec![leaves[2], left]),
+                Err(Error::InvalidProof)
+            );
+            assert_eq!(contract.get_vote(0, accounts.eve), Ok(Some(VoteChoice::No)));
+        }
+
+        #[ink::test]
+        fn test_lista_de_votantes_habilitados() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                allowlist_enabled: true,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Privada"), params(100)).unwrap();
+            contract.add_voter(accounts.bob).unwrap();
+
+            // Solo votan las cuentas habilitadas
+            assert!(contract.is_eligible(accounts.bob));
+            assert!(!contract.is_eligible(accounts.charlie));
+            set_caller(accounts.charlie);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::NotEligible));
+            assert_eq!(contract.add_voter(accounts.charlie), Err(Error::OnlyOwnerCanPerformAction));
+            set_caller(accounts.bob);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+
+            // Quitar una cuenta la inhabilita
+            set_caller(accounts.alice);
+            contract.remove_voter(accounts.bob).unwrap();
+            assert!(!contract.is_eligible(accounts.bob));
+
+            // Desactivar la restriccion habilita a todos
+            contract.set_allowlist_enabled(false).unwrap();
+            set_caller(accounts.charlie);
+            assert!(contract.vote(0, VoteChoice::No).is_ok());
+        }
+
+        #[ink::test]
+        fn test_rol_de_moderador() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Moderada"), params(100)).unwrap();
+            contract.create_proposal(String::from("Eliminada"), params(100)).unwrap();
+
+            // Solo el owner designa moderadores
+            set_caller(accounts.bob);
+            assert_eq!(contract.add_moderator(accounts.bob), Err(Error::OnlyOwnerCanPerformAction));
+            set_caller(accounts.alice);
+            contract.add_moderator(accounts.bob).unwrap();
+            assert!(contract.is_moderator(accounts.bob));
+
+            // El moderador cierra y elimina, pero no administra el contrato
+            set_caller(accounts.bob);
+            assert!(contract.close_proposal(0).is_ok());
+            assert!(contract.delete_proposal(1).is_ok());
+            assert_eq!(contract.pause(), Err(Error::OnlyOwnerCanPerformAction));
+            assert_eq!(contract.add_moderator(accounts.charlie), Err(Error::OnlyOwnerCanPerformAction));
+
+            // Al quitarle el rol pierde los permisos
+            set_caller(accounts.alice);
+            contract.remove_moderator(accounts.bob).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(contract.delete_proposal(0), Err(Error::Unauthorized));
+        }
+
+        #[ink::test]
+        fn test_transferencia_de_propiedad() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
 
-        /// Función auxiliar para simular quién firma la transacción
-        fn set_caller(account: AccountId) {
-            test::set_caller::<ink::env::DefaultEnvironment>(account);
+            // Solo el owner puede iniciar la transferencia
+            set_caller(accounts.bob);
+            assert_eq!(contract.transfer_ownership(accounts.bob), Err(Error::OnlyOwnerCanPerformAction));
+            set_caller(accounts.alice);
+            assert!(contract.transfer_ownership(accounts.bob).is_ok());
+
+            // Hasta aceptar, Alice sigue siendo owner
+            set_caller(accounts.charlie);
+            assert_eq!(contract.accept_ownership(), Err(Error::NotPendingOwner));
+            set_caller(accounts.alice);
+            assert!(contract.create_proposal(String::from("Antes"), params(100)).is_ok());
+
+            // Bob acepta y pasa a ser el owner
+            set_caller(accounts.bob);
+            assert!(contract.accept_ownership().is_ok());
+            assert!(contract.create_proposal(String::from("Despues"), params(100)).is_ok());
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.create_proposal(String::from("Rechazada"), params(100)),
+                Err(Error::OnlyOwnerCanPerformAction)
+            );
         }
 
         #[ink::test]
-        fn test_inicializacion_y_acceso_owner() {
+        fn test_owner_sin_voto() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                owner_can_vote: false,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Conflicto"), params(100)).unwrap();
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::OwnerCannotVote));
+            contract.transfer_ownership(accounts.bob).unwrap();
+
+            // La restricción pasa al nuevo owner y Alice recupera el voto
+            set_caller(accounts.bob);
+            contract.accept_ownership().unwrap();
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::OwnerCannotVote));
+            set_caller(accounts.alice);
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+        }
+
+        #[ink::test]
+        fn test_creador_sin_voto() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                creator_can_vote: false,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Propia"), params(100)).unwrap();
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::CreatorCannotVote));
+            contract.transfer_ownership(accounts.bob).unwrap();
+
+            // La restricción sigue al creador aunque deje de ser owner; el resto vota normalmente
+            set_caller(accounts.bob);
+            contract.accept_ownership().unwrap();
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
+            set_caller(accounts.charlie);
+            assert!(contract.vote(0, VoteChoice::No).is_ok());
+            set_caller(accounts.alice);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::CreatorCannotVote));
+            assert_eq!(contract.get_proposal(0).unwrap().yes_votes, 1);
+        }
+
+        #[ink::test]
+        fn test_actualizacion_solo_owner() {
+            // El entorno off-chain no soporta set_code_hash; se verifica el control de acceso
             let mut contract = VotingSystem::new();
             let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
-            
-            //  Verificar inicializacion
-            assert_eq!(contract.total_proposals(), 0);
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.set_code_hash(Hash::from([1u8; 32])),
+                Err(Error::OnlyOwnerCanPerformAction)
+            );
+        }
 
-            // Verificar control de acceso para el owner 
-            set_caller(accounts.bob); // Bob intenta crear
-            let res = contract.create_proposal(String::from("Falla"));
-            assert_eq!(res, Err(Error::OnlyOwnerCanPerformAction));
+        #[ink::test]
+        fn test_historial_de_owners() {
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            test::set_block_timestamp::<ink::env::DefaultEnvironment>(100);
+            let mut contract = VotingSystem::new();
+            assert_eq!(contract.owner_history(0, 10), vec![(accounts.alice, 100)]);
+
+            // La propuesta de transferencia no cambia el historial; la aceptación sí
+            contract.transfer_ownership(accounts.bob).unwrap();
+            assert_eq!(contract.owner_history_count(), 1);
+            test::set_block_timestamp::<ink::env::DefaultEnvironment>(200);
+            set_caller(accounts.bob);
+            contract.accept_ownership().unwrap();
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let transferred =
+                <OwnershipTransferred as ink::scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((transferred.previous, transferred.new), (accounts.alice, accounts.bob));
+
+            // La renuncia registra la cuenta nula
+            test::set_block_timestamp::<ink::env::DefaultEnvironment>(300);
+            contract.renounce_ownership().unwrap();
+            let zero = AccountId::from([0u8; 32]);
+            assert_eq!(
+                contract.owner_history(0, 10),
+                vec![(accounts.alice, 100), (accounts.bob, 200), (zero, 300)]
+            );
+            assert_eq!(contract.owner_history(2, 10), vec![(zero, 300)]);
+            assert_eq!(contract.owner_history_count(), 3);
         }
 
         #[ink::test]
-        fn test_creacion_propuestas_y_consulta() {
-            // Crear contrato y establecer caller como owner
+        fn test_renuncia_de_propiedad() {
             let mut contract = VotingSystem::new();
             let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
-            set_caller(accounts.alice); // Alice es owner por defecto en el constructor
+            assert!(!contract.is_ownerless());
 
-            // Crear multiples propuestas 
-            assert_eq!(contract.create_proposal(String::from("Propuestarda0")), Ok(0));
-            assert_eq!(contract.create_proposal(String::from("Propuestarda00")), Ok(1));
-            // Verificar conteo de propuestas
-            assert_eq!(contract.total_proposals(), 2);
+            // Solo el owner puede renunciar
+            set_caller(accounts.bob);
+            assert_eq!(contract.renounce_ownership(), Err(Error::OnlyOwnerCanPerformAction));
+            set_caller(accounts.alice);
+            assert!(contract.renounce_ownership().is_ok());
+            assert!(contract.is_ownerless());
 
-            // Verificar datos públicos 
-            let proposal = contract.get_proposal(0).unwrap();
-            assert_eq!(proposal.0, "Propuestarda0"); // .0 es el descripcion
-            assert_eq!(proposal.1, 0);             // .1 son los votos iniciales
+            // Ya no es posible administrar el contrato
+            assert_eq!(
+                contract.create_proposal(String::from("Sin owner"), params(100)),
+                Err(Error::OnlyOwnerCanPerformAction)
+            );
+            assert_eq!(contract.transfer_ownership(accounts.bob), Err(Error::OnlyOwnerCanPerformAction));
+
+            // La cuenta nula guardada como owner tampoco puede administrar
+            let zero = AccountId::from([0u8; 32]);
+            assert!(contract.is_zero(zero));
+            assert!(!contract.is_zero(accounts.alice));
+            set_caller(zero);
+            assert_eq!(
+                contract.create_proposal(String::from("Nula"), params(100)),
+                Err(Error::OnlyOwnerCanPerformAction)
+            );
+            assert_eq!(contract.transfer_ownership(zero), Err(Error::OnlyOwnerCanPerformAction));
         }
 
         #[ink::test]
-        fn test_registro_votos_exitoso() {
-            // Crear contrato y propuesta
+        fn test_evento_de_despliegue() {
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            test::set_block_timestamp::<ink::env::DefaultEnvironment>(500);
+            let _contract = VotingSystem::new();
+            let first = test::recorded_events().next().unwrap();
+            let event = <ContractDeployed as ink::scale::Decode>::decode(&mut &first.data[..]).unwrap();
+            assert_eq!((event.owner, event.timestamp), (accounts.alice, 500));
+        }
+
+        #[ink::test]
+        fn test_consulta_de_administradores() {
             let mut contract = VotingSystem::new();
             let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
-            // Owner crea propuesta
+            assert_eq!(contract.get_owner(), accounts.alice);
+
+            // La consulta sigue a la transferencia una vez aceptada
+            contract.transfer_ownership(accounts.bob).unwrap();
+            assert_eq!(contract.get_owner(), accounts.alice);
+            set_caller(accounts.bob);
+            contract.accept_ownership().unwrap();
+            assert_eq!(contract.get_owner(), accounts.bob);
+
+            contract.add_moderator(accounts.charlie).unwrap();
+            contract.add_moderator(accounts.django).unwrap();
+            contract.add_moderator(accounts.eve).unwrap();
+            contract.add_moderator(accounts.charlie).unwrap();
+            assert_eq!(contract.get_moderator_count(), 3);
+            assert_eq!(contract.get_moderators(0, 10), vec![accounts.charlie, accounts.django, accounts.eve]);
+
+            // Al quitar uno, el último ocupa su lugar
+            contract.remove_moderator(accounts.charlie).unwrap();
+            contract.remove_moderator(accounts.frank).unwrap();
+            assert_eq!(contract.get_moderator_count(), 2);
+            assert_eq!(contract.get_moderators(0, 10), vec![accounts.eve, accounts.django]);
+            assert_eq!(contract.get_moderators(1, 1), vec![accounts.django]);
+            assert!(contract.get_moderators(5, 10).is_empty());
+        }
+
+        #[ink::test]
+        fn test_transferencia_a_cuenta_nula() {
+            let mut contract = VotingSystem::new();
+            set_caller(test::default_accounts::<ink::env::DefaultEnvironment>().alice);
+            let zero = AccountId::from([0u8; 32]);
+            assert_eq!(contract.transfer_ownership(zero), Err(Error::ZeroAccount));
+            set_caller(zero);
+            assert_eq!(contract.accept_ownership(), Err(Error::NotPendingOwner));
+        }
+
+        #[ink::test]
+        fn test_espera_entre_votos() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                vote_cooldown: 10,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
             set_caller(accounts.alice);
-            contract.create_proposal(String::from("Propuestarda1")).unwrap();
+            contract.create_proposal(String::from("Primera"), params(100)).unwrap();
+            contract.create_proposal(String::from("Segunda"), params(100)).unwrap();
 
-            // Distintos usuarios votan
+            // El segundo voto debe esperar la cantidad de bloques configurada
             set_caller(accounts.bob);
-            assert!(contract.vote(0).is_ok());
-            
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            test::set_block_number::<ink::env::DefaultEnvironment>(9);
+            assert_eq!(contract.vote(1, VoteChoice::Yes), Err(Error::VotingTooSoon));
+            // Otras cuentas no se ven afectadas
             set_caller(accounts.charlie);
-            assert!(contract.vote(0).is_ok());
-            // Verificar conteo de votos
-            let (_, votos) = contract.get_proposal(0).unwrap();
-            assert_eq!(votos, 2);
+            assert!(contract.vote(1, VoteChoice::No).is_ok());
+            set_caller(accounts.bob);
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            assert!(contract.vote(1, VoteChoice::Yes).is_ok());
         }
 
         #[ink::test]
@@ -221,13 +7344,296 @@ mod voting_system {
             let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
             // Owner crea propuesta
             set_caller(accounts.alice);
-            contract.create_proposal(String::from("Unico Voto")).unwrap();
+            contract.create_proposal(String::from("Unico Voto"), params(100)).unwrap();
             // Usuario vota
             set_caller(accounts.bob);
-            assert!(contract.vote(0).is_ok());
+            assert!(contract.vote(0, VoteChoice::Yes).is_ok());
             
             // Reversion al votar dos veces 
-            assert_eq!(contract.vote(0), Err(Error::AlreadyVoted));
+            assert_eq!(contract.vote(0, VoteChoice::No), Err(Error::AlreadyVoted));
+        }
+
+        #[ink::test]
+        fn test_retiro_de_voto() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Retiro"), params(100)).unwrap();
+
+            // No se puede retirar un voto inexistente
+            set_caller(accounts.bob);
+            assert_eq!(contract.revoke_vote(0), Err(Error::HasNotVoted));
+            assert_eq!(contract.revoke_vote(99), Err(Error::ProposalDoesNotExist));
+
+            // Votar, retirar y volver a votar con otra opcion
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert!(contract.revoke_vote(0).is_ok());
+            assert_eq!(contract.get_votes(0), Ok(0));
+            assert!(contract.vote(0, VoteChoice::No).is_ok());
+            let ProposalView { yes_votes: si, no_votes: no, .. } = contract.get_proposal(0).unwrap();
+            assert_eq!((si, no), (0, 1));
+        }
+
+        #[ink::test]
+        fn test_cambio_de_voto() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Cambio"), params(100)).unwrap();
+
+            // Sin voto previo no hay nada que cambiar
+            set_caller(accounts.bob);
+            assert_eq!(contract.change_vote(0, VoteChoice::No), Err(Error::HasNotVoted));
+
+            // El voto se mueve de opcion
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.change_vote(0, VoteChoice::Yes), Err(Error::SameChoice));
+            assert!(contract.change_vote(0, VoteChoice::No).is_ok());
+            let ProposalView { yes_votes: si, no_votes: no, .. } = contract.get_proposal(0).unwrap();
+            assert_eq!((si, no), (0, 1));
+        }
+
+        #[ink::test]
+        fn test_listado_de_votantes() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Votantes"), params(100)).unwrap();
+            assert_eq!(contract.get_voters(0, 0, 10), Ok(Vec::new()));
+            assert_eq!(contract.get_voters(99, 0, 10), Err(Error::ProposalDoesNotExist));
+
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::No).unwrap();
+            set_caller(accounts.django);
+            contract.vote(0, VoteChoice::Abstain).unwrap();
+            assert_eq!(
+                contract.get_voters(0, 0, 10),
+                Ok(Vec::from([accounts.bob, accounts.charlie, accounts.django]))
+            );
+            assert_eq!(contract.get_voter_count(0), Ok(3));
+
+            // Paginación por posición
+            assert_eq!(contract.get_voters(0, 1, 1), Ok(Vec::from([accounts.charlie])));
+            assert_eq!(contract.get_voters(0, 3, 10), Ok(Vec::new()));
+
+            // Retirar el voto quita a la cuenta de la lista y el último votante ocupa su lugar
+            set_caller(accounts.bob);
+            contract.revoke_vote(0).unwrap();
+            assert_eq!(contract.get_voters(0, 0, 10), Ok(Vec::from([accounts.django, accounts.charlie])));
+            assert_eq!(contract.get_voter_count(0), Ok(2));
+            set_caller(accounts.charlie);
+            contract.revoke_vote(0).unwrap();
+            assert_eq!(contract.get_voters(0, 0, 10), Ok(Vec::from([accounts.django])));
+        }
+
+        #[ink::test]
+        fn test_instantaneas_de_votos() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Histórica"), params(100)).unwrap();
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.snapshot(), Err(Error::OnlyOwnerCanPerformAction));
+
+            // Tomar instantáneas en dos bloques distintos
+            set_caller(accounts.alice);
+            test::set_block_number::<ink::env::DefaultEnvironment>(5);
+            contract.snapshot().unwrap();
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            set_caller(accounts.alice);
+            test::set_block_number::<ink::env::DefaultEnvironment>(8);
+            contract.snapshot().unwrap();
+            contract.snapshot().unwrap();
+
+            assert_eq!(contract.get_snapshot(5, 0), Ok(1));
+            assert_eq!(contract.get_snapshot(8, 0), Ok(2));
+            assert_eq!(contract.get_snapshot(6, 0), Err(Error::SnapshotNotFound));
+            assert_eq!(contract.get_snapshot_blocks(0, 10), vec![5, 8]);
+            assert_eq!(contract.get_snapshot_blocks(1, 10), vec![8]);
+            assert_eq!(contract.get_snapshot_count(), 2);
+        }
+
+        #[ink::test]
+        fn test_estadisticas_globales() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Primera"), params(100)).unwrap();
+            contract.create_proposal(String::from("Segunda"), params(100)).unwrap();
+            assert_eq!(contract.stats(), (2, 0, 0));
+
+            // Una misma cuenta en dos propuestas cuenta una sola vez como votante
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            contract.vote(1, VoteChoice::No).unwrap();
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.stats(), (2, 3, 2));
+
+            // Eliminar una propuesta descuenta sus votos pero no los votantes
+            set_caller(accounts.alice);
+            contract.delete_proposal(0).unwrap();
+            assert_eq!(contract.stats(), (2, 1, 2));
+            set_caller(accounts.bob);
+            contract.revoke_vote(1).unwrap();
+            assert_eq!(contract.stats(), (2, 0, 2));
+        }
+
+        #[ink::test]
+        fn test_motivo_del_voto() {
+            let mut contract = VotingSystem::new_with_limits(32);
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Justificada"), params(100)).unwrap();
+
+            set_caller(accounts.bob);
+            let reason = String::from("Reduce costos");
+            contract.vote_with_reason(0, VoteChoice::Yes, reason.clone()).unwrap();
+            assert_eq!(contract.get_vote_reason(0, accounts.bob), Ok(Some(reason.clone())));
+            let last = test::recorded_events().last().unwrap();
+            let event = <VoteCastWithReason as ink::scale::Decode>::decode(&mut &last.data[..]).unwrap();
+            assert_eq!(event.reason, reason);
+
+            // Motivo demasiado largo, voto sin motivo y propuesta inexistente
+            set_caller(accounts.charlie);
+            let long_reason = String::from("Un motivo que supera los treinta y dos bytes");
+            assert_eq!(
+                contract.vote_with_reason(0, VoteChoice::No, long_reason),
+                Err(Error::ReasonTooLong)
+            );
+            contract.vote(0, VoteChoice::No).unwrap();
+            assert_eq!(contract.get_vote_reason(0, accounts.charlie), Ok(None));
+            assert_eq!(contract.get_vote_reason(99, accounts.bob), Err(Error::ProposalDoesNotExist));
+        }
+
+        #[ink::test]
+        fn test_consulta_has_voted() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Consulta"), params(100)).unwrap();
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+
+            // Voto, sin voto y propuesta inexistente
+            assert!(contract.has_voted(0, accounts.bob));
+            assert!(!contract.has_voted(0, accounts.charlie));
+            assert!(!contract.has_voted(99, accounts.bob));
+        }
+
+        #[ink::test]
+        fn test_historial_de_votos() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            for title in ["A", "B", "C"] {
+                contract.create_proposal(String::from(title), params(100)).unwrap();
+            }
+            set_caller(accounts.bob);
+            contract.vote(2, VoteChoice::Yes).unwrap();
+            contract.vote(0, VoteChoice::No).unwrap();
+            contract.vote(1, VoteChoice::Abstain).unwrap();
+            assert_eq!(contract.voting_history(accounts.bob, 0, 10), vec![2, 0, 1]);
+            assert_eq!(contract.voting_history(accounts.bob, 1, 1), vec![0]);
+
+            // Retirar el voto o eliminar la propuesta la saca del historial
+            contract.revoke_vote(0).unwrap();
+            set_caller(accounts.alice);
+            contract.delete_proposal(1).unwrap();
+            assert_eq!(contract.voting_history(accounts.bob, 0, 10), vec![2]);
+            assert_eq!(contract.voting_history_count(accounts.bob), 1);
+            assert_eq!(contract.voting_history_count(accounts.charlie), 0);
+        }
+
+        #[ink::test]
+        fn test_simulacion_de_voto() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Simulada"), params(10)).unwrap();
+            contract.blacklist(accounts.eve).unwrap();
+
+            // Sin errores la simulación coincide con el voto real y no lo registra
+            assert_eq!(contract.can_vote(0, accounts.bob), Ok(()));
+            assert!(!contract.has_voted(0, accounts.bob));
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+
+            // Retorna el mismo error que el voto
+            assert_eq!(contract.can_vote(0, accounts.bob), Err(Error::AlreadyVoted));
+            assert_eq!(contract.can_vote(0, accounts.eve), Err(Error::AccountBlacklisted));
+            assert_eq!(contract.can_vote(99, accounts.bob), Err(Error::ProposalDoesNotExist));
+            set_caller(accounts.alice);
+            contract.set_voting_paused(true).unwrap();
+            assert_eq!(contract.can_vote(0, accounts.charlie), Err(Error::VotingPaused));
+            contract.set_voting_paused(false).unwrap();
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(contract.can_vote(0, accounts.charlie), Err(Error::VotingClosed));
+            set_caller(accounts.charlie);
+            assert_eq!(contract.vote(0, VoteChoice::Yes), Err(Error::VotingClosed));
+        }
+
+        #[ink::test]
+        fn test_voto_por_lotes() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Primera"), params(100)).unwrap();
+            contract.create_proposal(String::from("Segunda"), params(100)).unwrap();
+            contract.create_proposal(String::from("Tercera"), params(100)).unwrap();
+            set_caller(accounts.bob);
+            contract.vote(1, VoteChoice::No).unwrap();
+
+            // Los fallos de un voto no cancelan el resto
+            let antes = test::recorded_events().count();
+            assert_eq!(
+                contract.vote_many(vec![0, 1, 99, 2]),
+                Ok(vec![Ok(()), Err(Error::AlreadyVoted), Err(Error::ProposalDoesNotExist), Ok(())])
+            );
+            assert_eq!(test::recorded_events().count(), antes + 2);
+            assert_eq!(contract.get_votes(0), Ok(1));
+            assert_eq!(contract.get_votes(2), Ok(1));
+            assert_eq!(contract.get_proposal(1).unwrap().no_votes, 1);
+
+            let enorme = vec![0; MAX_BATCH_SIZE as usize + 1];
+            assert_eq!(contract.vote_many(enorme), Err(Error::BatchTooLarge));
+        }
+
+        #[ink::test]
+        fn test_consulta_has_voted_por_lotes() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Primera"), params(100)).unwrap();
+            contract.create_proposal(String::from("Segunda"), params(100)).unwrap();
+            set_caller(accounts.bob);
+            contract.vote(1, VoteChoice::No).unwrap();
+
+            assert_eq!(
+                contract.has_voted_batch(accounts.bob, vec![0, 1, 99]),
+                Ok(vec![false, true, false])
+            );
+            let enorme = vec![0; MAX_LOOKUP_SIZE as usize + 1];
+            assert_eq!(contract.has_voted_batch(accounts.bob, enorme), Err(Error::BatchTooLarge));
+        }
+
+        #[ink::test]
+        fn test_consulta_de_opcion_votada() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Consulta"), params(100)).unwrap();
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Abstain).unwrap();
+
+            // Opción votada, sin voto y propuesta inexistente
+            assert_eq!(contract.get_vote(0, accounts.bob), Ok(Some(VoteChoice::Abstain)));
+            assert_eq!(contract.get_vote(0, accounts.charlie), Ok(None));
+            assert_eq!(contract.get_vote(99, accounts.bob), Err(Error::ProposalDoesNotExist));
         }
 
         #[ink::test]
@@ -238,7 +7644,7 @@ mod voting_system {
             set_caller(accounts.bob);
 
             // Reversion al votar propuestas inexistentes 
-            assert_eq!(contract.vote(99), Err(Error::ProposalDoesNotExist));
+            assert_eq!(contract.vote(99, VoteChoice::Yes), Err(Error::ProposalDoesNotExist));
             assert_eq!(contract.get_proposal(99), Err(Error::ProposalDoesNotExist));
         }
 
@@ -249,16 +7655,129 @@ mod voting_system {
             let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
             // Owner crea propuesta
             set_caller(accounts.alice);
-            contract.create_proposal(String::from("Evento Testeardo")).unwrap();
+            contract.create_proposal(String::from("Evento Testeardo"), params(100)).unwrap();
             // Usuario vota
             set_caller(accounts.bob);
-            contract.vote(0).unwrap();
+            contract.vote(0, VoteChoice::Yes).unwrap();
 
             // Verificar eventos 
             let emitted_events = test::recorded_events().collect::<Vec<_>>();
             // Debería haber al menos 2 eventos emitidos
             assert!(emitted_events.len() >= 2);
         }
+
+        #[ink::test]
+        fn test_evento_de_voto_con_totales() {
+            let mut contract = VotingSystem::new();
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Totales"), params(100)).unwrap();
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            set_caller(accounts.charlie);
+            test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+
+            // El evento trae el total actualizado de la opción y la marca de tiempo
+            let last = test::recorded_events().last().unwrap();
+            let event = <VoteCast as ink::scale::Decode>::decode(&mut &last.data[..]).unwrap();
+            assert_eq!(event.voter, accounts.charlie);
+            assert_eq!(event.new_total, 2);
+            assert_eq!(event.timestamp, 1_000);
+        }
+
+        #[ink::test]
+        fn test_decaimiento_del_peso() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                decay_mode: true,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            contract.create_proposal(String::from("Decae"), params(100)).unwrap();
+            let last_weight = || {
+                let last = test::recorded_events().last().unwrap();
+                <VoteCast as ink::scale::Decode>::decode(&mut &last.data[..]).unwrap().weight
+            };
+
+            // Al inicio, a mitad y en el último bloque de la votación
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(last_weight(), 100);
+            test::set_block_number::<ink::env::DefaultEnvironment>(50);
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(last_weight(), 50);
+            test::set_block_number::<ink::env::DefaultEnvironment>(99);
+            set_caller(accounts.django);
+            contract.vote(0, VoteChoice::No).unwrap();
+            assert_eq!(last_weight(), 1);
+
+            // Los contadores están en centésimos de voto
+            let ProposalView { yes_votes: si, no_votes: no, vote_unit, .. } = contract.get_proposal(0).unwrap();
+            assert_eq!((si, no, vote_unit), (150, 1, 100));
+        }
+
+        #[ink::test]
+        fn test_quorum_y_umbral_en_modo_de_decaimiento() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                decay_mode: true,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let exigente = ProposalParams {
+                quorum: 2,
+                threshold: 2,
+                max_weight_per_voter: 1,
+                ..params(100)
+            };
+            contract.create_proposal(String::from("Escalada"), exigente).unwrap();
+
+            // Un voto completo equivale a 100 unidades y no alcanza un quórum de 2 votos
+            set_caller(accounts.bob);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.get_votes(0), Ok(100));
+            assert_eq!(contract.is_quorum_reached(0), Ok(false));
+
+            // A mitad del plazo suma medio voto: 1,5 votos tampoco alcanzan
+            test::set_block_number::<ink::env::DefaultEnvironment>(50);
+            set_caller(accounts.charlie);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.is_quorum_reached(0), Ok(false));
+            set_caller(accounts.django);
+            contract.vote(0, VoteChoice::Yes).unwrap();
+            assert_eq!(contract.is_quorum_reached(0), Ok(true));
+            test::set_block_number::<ink::env::DefaultEnvironment>(100);
+            assert_eq!(contract.has_passed(0), Ok(true));
+        }
+
+        #[ink::test]
+        fn test_voto_secreto_en_modo_de_decaimiento() {
+            let mut contract = VotingSystem::new_with_settings(Settings {
+                decay_mode: true,
+                ..Default::default()
+            });
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            set_caller(accounts.alice);
+            let secreta = ProposalParams {
+                commit_reveal: true,
+                quorum: 1,
+                ..params(10)
+            };
+            contract.create_proposal(String::from("Secreta"), secreta).unwrap();
+            let salt = [3u8; 32];
+            let mut compromiso = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(&(VoteChoice::Yes, salt).encode(), &mut compromiso);
+
+            // El voto revelado tras el plazo cuenta como un voto completo, 100 unidades
+            set_caller(accounts.bob);
+            contract.commit_vote(0, compromiso).unwrap();
+            test::set_block_number::<ink::env::DefaultEnvironment>(10);
+            contract.reveal_vote(0, VoteChoice::Yes, salt).unwrap();
+            assert_eq!(contract.get_votes(0), Ok(100));
+            assert_eq!(contract.is_quorum_reached(0), Ok(true));
+        }
     
       
     